@@ -0,0 +1,44 @@
+//! Compares `gregorian_to_jalali` (cycle constants) with `gregorian_to_jalali_via_jdn` (JDN pivot) and
+//! with lookups in a precomputed `JalaliConversionTable`.
+//!
+//! Run with `cargo bench`. This is a plain timing loop so the crate stays free of dependencies.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use jalali_rs::{gregorian_to_jalali, gregorian_to_jalali_via_jdn, JalaliConversionTable};
+
+const ROUNDS: u32 = 20;
+
+fn bench<T>(name: &str, convert: impl Fn(i32, usize, i32) -> T) -> Duration {
+    let mut best = Duration::MAX;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        for year in 1900..2100 {
+            for month in 1..=12 {
+                for day in 1..=28 {
+                    black_box(convert(black_box(year), black_box(month), black_box(day)));
+                }
+            }
+        }
+        best = best.min(start.elapsed());
+    }
+    let conversions = 200 * 12 * 28;
+    println!(
+        "{:<28} {:>10.2?} per {} conversions ({:.1} ns each)",
+        name,
+        best,
+        conversions,
+        best.as_nanos() as f64 / conversions as f64
+    );
+    best
+}
+
+fn main() {
+    bench("gregorian_to_jalali", gregorian_to_jalali);
+    bench("gregorian_to_jalali_via_jdn", gregorian_to_jalali_via_jdn);
+    let table = JalaliConversionTable::new(1278, 1479).expect("valid window");
+    bench("JalaliConversionTable", |year, month, day| {
+        table.from_gregorian(year, month as u32, day as u32)
+    });
+}
@@ -7,9 +7,36 @@
 //!
 //! ## Core Features
 //! - Convert Gregorian to Jalali dates and vice versa.
-//! - Convert Unix timestamps to Jalali dates and vice versa (assuming UTC midnight; negative timestamps return `None`).
+//! - Convert Unix timestamps to Jalali dates and vice versa (assuming UTC midnight; negative timestamps are
+//!   supported and floor to the previous UTC day).
 //! - Parse and format date strings with custom separators, handling Persian/Arabic digits automatically.
 //! - Convert between Latin, Persian, and Arabic digits for flexible user input.
+//! - Work with the validated `JalaliDate` and `GregorianDate` types instead of `(year, month, day)` tuples,
+//!   adding and subtracting days with `+` and `-`.
+//! - Import the common types and functions at once with `use jalali_rs::prelude::*`.
+//!
+//! ## Optional Features
+//!
+//! The crate has no dependencies by default. Cargo features add integrations:
+//!
+//! - `serde`: `Serialize` and `Deserialize` for `JalaliDate`, as a "1404-10-06" string, and the
+//!   `serde_int` module for fields stored as a YYYYMMDD integer instead.
+//! - `chrono`: conversions between `JalaliDate` and `chrono::NaiveDate`.
+//!
+//! ## Supported Range
+//!
+//! Conversions use the arithmetic 33-year leap cycle and the proleptic Gregorian calendar, with floor
+//! division so that years before the epochs follow the same rules. They are verified to round-trip day
+//! by day, including the early years 1, 100 and 500 and years before the Gregorian year 0. For early
+//! centuries the arithmetic cycle can differ by a day from astronomical tables: Farvardin 1, year 1 is
+//! 622-03-21 here, versus 622-03-22 (19 March 622 Julian) in the astronomical calendar.
+//!
+//! The Gregorian side is always the proleptic Gregorian calendar, including before the reform of
+//! October 1582. For dates from historical sources before then, which use the Julian calendar, use
+//! `jalali_to_julian` and `julian_to_jalali` instead.
+//!
+//! A `JalaliDate` holds any year from `JALALI_MIN_YEAR` to `JALALI_MAX_YEAR` (the full `i32` range),
+//! between the sentinels `JalaliDate::MIN` and `JalaliDate::MAX`.
 //!
 //! ## Usage
 //!
@@ -49,8 +76,75 @@
 //! println!("Latin digits: {}", latin); // 1404-10-06
 //! ```
 
+/// Julian Day Numbers of the calendar epochs the crate's conversions are anchored to.
+///
+/// These are the pivots used internally; they're exposed so advanced users can build their own
+/// conversions on top of the same Julian Day Number arithmetic, along with `gregorian_to_jdn`,
+/// `jalali_to_jdn` and their inverses.
+pub mod epoch {
+    /// Julian Day Number of the Unix epoch, 1970-01-01 (Gregorian).
+    pub const UNIX_EPOCH_JDN: i64 = 2_440_588;
+
+    /// Julian Day Number of 1 Farvardin 1 (Jalali), as computed by the crate's 33-year arithmetic cycle.
+    pub const JALALI_EPOCH_JDN: i64 = 1_948_320;
+
+    /// Julian Day Number of 0001-01-01 in the proleptic Gregorian calendar.
+    pub const GREGORIAN_EPOCH_JDN: i64 = 1_721_426;
+
+    /// Julian Day Number of 1 Muharram 1 (Hijri) in the civil tabular Islamic calendar.
+    pub const HIJRI_EPOCH_JDN: i64 = 1_948_440;
+}
+
+/// The crate's most used types and functions, for glob importing.
+///
+/// `use jalali_rs::prelude::*;` brings in:
+///
+/// - the types `JalaliDate`, `GregorianDate`, `JalaliDateTime`, `JalaliDateBuilder`, `JalaliWeekday`,
+///   `JalaliError` and `Calendar`;
+/// - the converters `gregorian_to_jalali`, `jalali_to_gregorian`, `unix_to_jalali`, `jalali_to_unix`
+///   and `convert`;
+/// - the string helpers `format_jalali`, `parse_gregorian_string_to_jalali_string`,
+///   `parse_jalali_string_to_gregorian_string`, `latin_digits_to_persian` and
+///   `persian_or_arabic_digits_to_latin`;
+/// - the checks `is_valid_jalali_date` and `is_jalali_leap_year`.
+///
+/// Everything stays available at its usual path in the crate root.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::prelude::*;
+///
+/// let date = JalaliDate::from_gregorian(2025, 12, 27).unwrap();
+/// assert_eq!(date.weekday(), JalaliWeekday::Shanbe);
+/// assert_eq!(format_jalali(1404, 10, 6, "%Y/%m/%d"), "1404/10/06");
+/// ```
+pub mod prelude {
+    pub use crate::{
+        Calendar, GregorianDate, JalaliDate, JalaliDateBuilder, JalaliDateTime, JalaliError, JalaliWeekday, convert,
+        format_jalali, gregorian_to_jalali, is_jalali_leap_year, is_valid_jalali_date, jalali_to_gregorian,
+        jalali_to_unix, latin_digits_to_persian, parse_gregorian_string_to_jalali_string,
+        parse_jalali_string_to_gregorian_string, persian_or_arabic_digits_to_latin, unix_to_jalali,
+    };
+}
+
+/// Iran Standard Time's offset from UTC in seconds (UTC+03:30).
+///
+/// Iran has observed no daylight saving time since 2022, so this is Tehran's offset all year. Pass it
+/// to `unix_to_jalali_with_offset` and `jalali_to_unix_with_offset` to get civil dates in Iran.
+pub const IRAN_STANDARD_OFFSET: i32 = 3 * 3600 + 1800;
+
+/// The earliest Jalali year a `JalaliDate` can hold, the first day of which is `JalaliDate::MIN`.
+pub const JALALI_MIN_YEAR: i32 = i32::MIN;
+
+/// The latest Jalali year a `JalaliDate` can hold, the last day of which is `JalaliDate::MAX`.
+pub const JALALI_MAX_YEAR: i32 = i32::MAX;
+
 /// Converts a Gregorian date to a Jalali (Persian) date.
 ///
+/// Months outside 1-12 carry into the neighbouring years (month 0 is the previous December, month 13
+/// the next January). See the crate-level "Supported Range" notes.
+///
 /// # Arguments
 ///
 /// * `gregorian_year` - The Gregorian year (e.g., 2025).
@@ -75,25 +169,31 @@ pub fn gregorian_to_jalali(
     // cumulative days at the end of each Gregorian month (non-leap year, adjusted later)
     let gregorian_cumulative_days: [i64; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
 
+    // carry months outside 1-12 into the neighbouring years (month 0 is the previous December)
+    let month_offset = gregorian_month as i64 - 1;
+    let gregorian_year: i64 = gregorian_year as i64 + month_offset.div_euclid(12);
+    let gregorian_month = month_offset.rem_euclid(12) as usize + 1;
+
     // adjust year for leap year calculation if month is after February
     let adjusted_year: i64 = if gregorian_month > 2 {
-        gregorian_year as i64 + 1
+        gregorian_year + 1
     } else {
-        gregorian_year as i64
+        gregorian_year
     };
 
     // calculate total days from a fixed epoch, including leap year adjustments
+    // (floor divisions keep the counts correct for years before the epoch)
     let mut total_days: i64 = 355666
-        + (365 * gregorian_year as i64)
-        + ((adjusted_year + 3) / 4)
-        - ((adjusted_year + 99) / 100)
-        + ((adjusted_year + 399) / 400)
+        + (365 * gregorian_year)
+        + (adjusted_year + 3).div_euclid(4)
+        - (adjusted_year + 99).div_euclid(100)
+        + (adjusted_year + 399).div_euclid(400)
         + gregorian_day as i64
         + gregorian_cumulative_days[gregorian_month - 1];
 
     // compute Jalali year using divisions based on Jalali cycle lengths
-    let mut jalali_year: i64 = -1595 + (33 * (total_days / 12053));
-    total_days %= 12053;
+    let mut jalali_year: i64 = -1595 + (33 * total_days.div_euclid(12053));
+    total_days = total_days.rem_euclid(12053);
     jalali_year += 4 * (total_days / 1461);
     total_days %= 1461;
 
@@ -117,8 +217,48 @@ pub fn gregorian_to_jalali(
     (jalali_year as i32, jalali_month, jalali_day)
 }
 
+/// Converts a Gregorian date to a Jalali (Persian) date through a Julian Day Number pivot.
+///
+/// The date is turned into a Julian Day Number, which is then split into 33-year Jalali cycles. It
+/// returns the same results as `gregorian_to_jalali` and is kept alongside it for comparison; see the
+/// `gregorian_to_jalali` benchmark.
+///
+/// Months outside 1-12 carry into the neighbouring years (month 0 is the previous December, month 13
+/// the next January). See the crate-level "Supported Range" notes.
+///
+/// # Arguments
+///
+/// * `gregorian_year` - The Gregorian year (e.g., 2025).
+/// * `gregorian_month` - The Gregorian month (1-12).
+/// * `gregorian_day` - The Gregorian day (1-31).
+///
+/// # Returns
+///
+/// A tuple containing (jalali_year, jalali_month, jalali_day).
+///
+/// # Examples
+///
+/// ```
+/// let (jy, jm, jd) = jalali_rs::gregorian_to_jalali_via_jdn(2025, 12, 27);
+/// assert_eq!((jy, jm, jd), (1404, 10, 6));
+/// ```
+pub fn gregorian_to_jalali_via_jdn(
+    gregorian_year: i32,
+    gregorian_month: usize,
+    gregorian_day: i32,
+) -> (i32, u32, u32) {
+    // carry months outside 1-12 into the neighbouring years (month 0 is the previous December)
+    let month_offset = gregorian_month as i64 - 1;
+    let gregorian_year = gregorian_year as i64 + month_offset.div_euclid(12);
+    let gregorian_month = month_offset.rem_euclid(12) + 1;
+
+    jdn_to_jalali(gregorian_to_jdn(gregorian_year as i32, gregorian_month as i32, gregorian_day))
+}
+
 /// Converts a Jalali (Persian) date to a Gregorian date.
 ///
+/// See the crate-level "Supported Range" notes.
+///
 /// # Arguments
 ///
 /// * `jalali_year` - The Jalali year (e.g., 1404).
@@ -140,72 +280,61 @@ pub fn jalali_to_gregorian(
     jalali_month: usize,
     jalali_day: i32,
 ) -> (i32, u32, u32) {
-    let  jalali_year_i64: i64 = jalali_year as i64 + 1595;
-
-    // calculate total days from a fixed epoch, including Jalali leap adjustments
-    let mut total_days: i64 = -355668
-        + (365 * jalali_year_i64)
-        + ((jalali_year_i64 / 33) * 8)
-        + (((jalali_year_i64 % 33) + 3) / 4)
-        + jalali_day as i64
-        + if jalali_month < 7 {
-        (jalali_month as i64 - 1) * 31
-    } else {
-        ((jalali_month as i64 - 7) * 30) + 186
-    };
+    let gregorian = days_to_gregorian(jalali_to_days(jalali_year, jalali_month as i64, jalali_day as i64));
 
-    // compute Gregorian year using divisions based on Gregorian cycle lengths
-    let mut gregorian_year: i64 = 400 * (total_days / 146097);
-    total_days %= 146097;
-    if total_days > 36524 {
-        total_days -= 1;
-        gregorian_year += 100 * (total_days / 36524);
-        total_days %= 36524;
-        if total_days >= 365 {
-            total_days += 1;
-        }
-    }
-    gregorian_year += 4 * (total_days / 1461);
-    total_days %= 1461;
-    if total_days > 365 {
-        gregorian_year += (total_days - 1) / 365;
-        total_days = (total_days - 1) % 365;
+    // cross-check valid dates against the inverse conversion in debug builds
+    #[cfg(debug_assertions)]
+    if (1..=12).contains(&jalali_month)
+        && jalali_day >= 1
+        && jalali_day as u32 <= jalali_month_length(jalali_year, jalali_month as u32)
+        && jalali_year <= i32::MAX - 622
+    {
+        debug_assert_eq!(
+            gregorian_to_jalali(gregorian.0, gregorian.1 as usize, gregorian.2 as i32),
+            (jalali_year, jalali_month as u32, jalali_day as u32),
+            "jalali_to_gregorian does not round-trip"
+        );
     }
 
-    // determine Gregorian day and advance through months
-    let mut gregorian_day: i64 = total_days + 1;
-
-    // array of days in each Gregorian month, adjusting February for leap year
-    let is_leap_year = (gregorian_year % 4 == 0 && gregorian_year % 100 != 0)
-        || (gregorian_year % 400 == 0);
-    let gregorian_days_in_month: [i64; 13] = [
-        0,
-        31,
-        if is_leap_year { 29 } else { 28 },
-        31,
-        30,
-        31,
-        30,
-        31,
-        31,
-        30,
-        31,
-        30,
-        31,
-    ];
-
-    let mut gregorian_month: usize = 0;
-    while gregorian_month < 13 && gregorian_day > gregorian_days_in_month[gregorian_month] {
-        gregorian_day -= gregorian_days_in_month[gregorian_month];
-        gregorian_month += 1;
-    }
+    gregorian
+}
 
-    (gregorian_year as i32, gregorian_month as u32, gregorian_day as u32)
+/// Converts a Jalali (Persian) date to a Gregorian date and also returns its Julian Day Number.
+///
+/// Both values come out of the same day count, so callers that need the JDN as a pivot
+/// for further arithmetic don't have to recompute it from the Gregorian result.
+///
+/// # Arguments
+///
+/// * `jalali_year` - The Jalali year (e.g., 1404).
+/// * `jalali_month` - The Jalali month (1-12).
+/// * `jalali_day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// A tuple containing ((gregorian_year, gregorian_month, gregorian_day), julian_day_number).
+///
+/// # Examples
+///
+/// ```
+/// let ((gy, gm, gd), jdn) = jalali_rs::jalali_to_gregorian_jdn(1404, 10, 6);
+/// assert_eq!((gy, gm, gd), (2025, 12, 27));
+/// assert_eq!(jdn, 2461037);
+/// ```
+pub fn jalali_to_gregorian_jdn(
+    jalali_year: i32,
+    jalali_month: u32,
+    jalali_day: u32,
+) -> ((i32, u32, u32), i64) {
+    let total_days = jalali_to_days(jalali_year, jalali_month as i64, jalali_day as i64);
+    // day 0 of the count is 0000-01-01, one (leap) year before the Gregorian epoch
+    (days_to_gregorian(total_days), total_days + epoch::GREGORIAN_EPOCH_JDN - 366)
 }
 
 /// Converts a Unix timestamp (seconds since 1970-01-01 UTC) to a Jalali date.
 ///
-/// Returns `None` for negative timestamps or invalid calculations.
+/// Timestamps before 1970 floor to the UTC day they fall in, so -1 is 1348-10-10. Returns `None` if
+/// the date's year doesn't fit in an `i32`.
 ///
 /// # Arguments
 ///
@@ -226,14 +355,15 @@ pub fn jalali_to_gregorian(
 /// }
 /// ```
 pub fn unix_to_jalali(timestamp: i64) -> Option<(i32, u32, u32)> {
-    unix_to_gregorian(timestamp).map(|(gy, gm, gd)| {
-        gregorian_to_jalali(gy, gm as usize, gd as i32)
-    })
+    unix_to_gregorian(timestamp).and_then(|(gy, gm, gd)| convert(Calendar::Gregorian, Calendar::Jalali, gy, gm, gd))
 }
 
 /// Converts a Jalali date to a Unix timestamp (seconds since 1970-01-01 UTC at midnight).
 ///
-/// Returns `None` if the date is before 1970-01-01 or invalid.
+/// Dates before 1970-01-01 give negative timestamps. The conversion counts days directly rather than going
+/// through the Gregorian date, so it stays exact for every `i32` year. Even the furthest `i32` years are
+/// only about 7.8 × 10^11 days from 1970, well inside the `i64` range, so `None` is never returned; the
+/// `Option` is kept for compatibility.
 ///
 /// # Arguments
 ///
@@ -243,7 +373,7 @@ pub fn unix_to_jalali(timestamp: i64) -> Option<(i32, u32, u32)> {
 ///
 /// # Returns
 ///
-/// An `Option` containing the Unix timestamp or `None`.
+/// An `Option` containing the Unix timestamp, which is always `Some`.
 ///
 /// # Examples
 ///
@@ -253,13 +383,14 @@ pub fn unix_to_jalali(timestamp: i64) -> Option<(i32, u32, u32)> {
 /// }
 /// ```
 pub fn jalali_to_unix(jalali_year: i32, jalali_month: u32, jalali_day: u32) -> Option<i64> {
-    let (gy, gm, gd) = jalali_to_gregorian(jalali_year, jalali_month as usize, jalali_day as i32);
-    gregorian_to_unix(gy, gm, gd)
+    jalali_to_epoch_days(jalali_year, jalali_month, jalali_day).checked_mul(86_400)
 }
 
 /// Parses a Gregorian date string (e.g., "2025-12-27") and converts to Jalali string format.
 ///
-/// Handles Persian/Arabic digits in input. Returns `None` for invalid formats.
+/// Handles Persian/Arabic digits in input, as well as thousands marks (٬ or ,) between groups of
+/// three digits (e.g., "۱٬۴۰۴"). Returns `None` for invalid formats and for days past the end of the
+/// month.
 ///
 /// # Arguments
 ///
@@ -280,24 +411,19 @@ pub fn jalali_to_unix(jalali_year: i32, jalali_month: u32, jalali_day: u32) -> O
 /// assert_eq!(result_persian, Some("1404-10-06".to_string()));
 /// ```
 pub fn parse_gregorian_string_to_jalali_string(date_str: &str, separator: char) -> Option<String> {
-    let normalized = persian_or_arabic_digits_to_latin(date_str);
-    let parts: Vec<&str> = normalized.split(separator).collect();
-    if parts.len() != 3 {
+    let (gy, gm, gd) = parse_date_parts(date_str, separator).ok()?;
+    if !is_valid_gregorian_date(gy, gm, gd) {
         return None;
     }
-    let gy = parts[0].parse::<i32>().ok()?;
-    let gm = parts[1].parse::<usize>().ok()?;
-    let gd = parts[2].parse::<i32>().ok()?;
-    if gm < 1 || gm > 12 || gd < 1 || gd > 31 {
-        return None; // basic validation
-    }
-    let (jy, jm, jd) = gregorian_to_jalali(gy, gm, gd);
+    let (jy, jm, jd) = gregorian_to_jalali(gy, gm as usize, gd as i32);
     Some(format!("{:04}-{:02}-{:02}", jy, jm, jd))
 }
 
 /// Parses a Jalali date string (e.g., "1404-10-06") and converts to Gregorian string format.
 ///
-/// Handles Persian/Arabic digits in input. Returns `None` for invalid formats.
+/// Handles Persian/Arabic digits in input, as well as thousands marks (٬ or ,) between groups of
+/// three digits (e.g., "۱٬۴۰۴"). Returns `None` for invalid formats and for days past the end of the
+/// month; `try_parse_jalali` reports which of these went wrong.
 ///
 /// # Arguments
 ///
@@ -318,24 +444,145 @@ pub fn parse_gregorian_string_to_jalali_string(date_str: &str, separator: char)
 /// assert_eq!(result_persian, Some("2025-12-27".to_string()));
 /// ```
 pub fn parse_jalali_string_to_gregorian_string(date_str: &str, separator: char) -> Option<String> {
-    let normalized = persian_or_arabic_digits_to_latin(date_str);
-    let parts: Vec<&str> = normalized.split(separator).collect();
-    if parts.len() != 3 {
-        return None;
+    let (jy, jm, jd) = parse_jalali_date(date_str, separator)?;
+    let (gy, gm, gd) = jalali_to_gregorian(jy, jm as usize, jd as i32);
+    Some(format!("{:04}-{:02}-{:02}", gy, gm, gd))
+}
+
+/// The reason a Jalali date couldn't be parsed or built.
+///
+/// Reported by `parse_jalali_string_to_gregorian_string_detailed`, `JalaliDate`'s `FromStr` and
+/// `JalaliDateBuilder::build`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JalaliError {
+    /// The string doesn't have exactly three fields, or a field is empty.
+    InvalidFormat,
+    /// A field contains a character that isn't a digit.
+    InvalidDigit,
+    /// The month is outside 1-12.
+    InvalidMonth,
+    /// The day is zero or past the end of the month.
+    InvalidDay,
+    /// A `JalaliDateBuilder` was built without a year.
+    MissingYear,
+    /// A `JalaliDateBuilder` was built without a month.
+    MissingMonth,
+    /// A `JalaliDateBuilder` was built without a day.
+    MissingDay,
+}
+
+impl std::fmt::Display for JalaliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            JalaliError::InvalidFormat => "expected year, month and day fields",
+            JalaliError::InvalidDigit => "invalid digit",
+            JalaliError::InvalidMonth => "month out of range",
+            JalaliError::InvalidDay => "day out of range",
+            JalaliError::MissingYear => "missing year",
+            JalaliError::MissingMonth => "missing month",
+            JalaliError::MissingDay => "missing day",
+        };
+        f.write_str(message)
     }
-    let jy = parts[0].parse::<i32>().ok()?;
-    let jm = parts[1].parse::<usize>().ok()?;
-    let jd = parts[2].parse::<i32>().ok()?;
-    if jm < 1 || jm > 12 || jd < 1 || jd > 31 {
-        return None; // basic validation
+}
+
+impl std::error::Error for JalaliError {}
+
+/// The broad reason a Jalali date string failed to parse, as reported by `try_parse_jalali`.
+///
+/// Coarser than `JalaliError`, for callers that only need to tell the user what kind of mistake
+/// they made.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateParseError {
+    /// The string doesn't split into exactly three parts at the separator.
+    WrongPartCount,
+    /// A part is empty or isn't a number.
+    NotNumeric,
+    /// A part is a number, but the year doesn't fit in an `i32` or the month or day doesn't exist.
+    OutOfRange,
+}
+
+impl std::fmt::Display for DateParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            DateParseError::WrongPartCount => "expected year, month and day separated by the separator",
+            DateParseError::NotNumeric => "date part is not a number",
+            DateParseError::OutOfRange => "date part out of range",
+        };
+        f.write_str(message)
     }
-    let (gy, gm, gd) = jalali_to_gregorian(jy, jm, jd);
-    Some(format!("{:04}-{:02}-{:02}", gy, gm, gd))
+}
+
+impl std::error::Error for DateParseError {}
+
+/// Parses a Jalali date string into a `JalaliDate`, reporting why parsing failed.
+///
+/// Accepts the same input as `parse_jalali_string_to_gregorian_string`: Persian/Arabic digits are
+/// normalized and thousands marks (٬ or ,) between groups of three digits are ignored.
+///
+/// # Arguments
+///
+/// * `date_str` - The Jalali date string (e.g., "1404/10/06" or "۱۴۰۴/۱۰/۰۶").
+/// * `separator` - The separator character used in the input string (e.g., '/').
+///
+/// # Returns
+///
+/// The parsed `JalaliDate`, or the `DateParseError` describing the problem.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::{try_parse_jalali, DateParseError};
+///
+/// assert_eq!(try_parse_jalali("۱۴۰۴/۱۰/۰۶", '/').unwrap().to_string(), "1404-10-06");
+/// assert_eq!(try_parse_jalali("1404/10", '/'), Err(DateParseError::WrongPartCount));
+/// assert_eq!(try_parse_jalali("1404/1o/06", '/'), Err(DateParseError::NotNumeric));
+/// assert_eq!(try_parse_jalali("1404/12/30", '/'), Err(DateParseError::OutOfRange));
+/// ```
+pub fn try_parse_jalali(date_str: &str, separator: char) -> Result<JalaliDate, DateParseError> {
+    let (jy, jm, jd) = parse_date_parts(date_str, separator)?;
+    JalaliDate::new(jy, jm, jd).ok_or(DateParseError::OutOfRange)
+}
+
+/// Parses a Jalali date string and converts it to a Gregorian date string, reporting where parsing failed.
+///
+/// Accepts the same input as `parse_jalali_string_to_gregorian_string`. On failure the error carries the
+/// character index (not the byte index) of the offending character: the bad digit for `InvalidDigit`,
+/// the start of the field for `InvalidMonth`, `InvalidDay` and empty fields, and the extra separator or the
+/// end of the string for a wrong number of fields.
+///
+/// # Arguments
+///
+/// * `date_str` - The Jalali date string (e.g., "1400/12/10" or "۱۴۰۰/۱۲/۱۰").
+/// * `separator` - The separator character used in the input string (e.g., '/').
+///
+/// # Returns
+///
+/// The Gregorian date string in "YYYY-MM-DD" format, or the error and the character index where it was found.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::JalaliError;
+///
+/// let result = jalali_rs::parse_jalali_string_to_gregorian_string_detailed("1400/12/10", '/');
+/// assert_eq!(result, Ok("2022-03-01".to_string()));
+/// let result = jalali_rs::parse_jalali_string_to_gregorian_string_detailed("۱۴۰۰/1x/10", '/');
+/// assert_eq!(result, Err((JalaliError::InvalidDigit, 6)));
+/// ```
+pub fn parse_jalali_string_to_gregorian_string_detailed(
+    date_str: &str,
+    separator: char,
+) -> Result<String, (JalaliError, usize)> {
+    let (jy, jm, jd) = parse_jalali_date_detailed(date_str, separator)?;
+    let (gy, gm, gd) = jalali_to_gregorian(jy, jm as usize, jd as i32);
+    Ok(format!("{:04}-{:02}-{:02}", gy, gm, gd))
 }
 
 /// Converts Latin digits in a string to Persian digits.
 ///
-/// Non-digit characters remain unchanged.
+/// Non-digit characters remain unchanged. The function is total: it never panics, whatever Unicode
+/// the input contains.
 ///
 /// # Arguments
 ///
@@ -355,7 +602,7 @@ pub fn latin_digits_to_persian(s: &str) -> String {
     s.chars()
         .map(|c| {
             if c.is_ascii_digit() {
-                char::from_u32('۰' as u32 + (c as u32 - '0' as u32)).unwrap()
+                PERSIAN_DIGITS[(c as u8 - b'0') as usize]
             } else {
                 c
             }
@@ -366,6 +613,7 @@ pub fn latin_digits_to_persian(s: &str) -> String {
 /// Converts Persian or Arabic digits in a string to Latin digits.
 ///
 /// Non-digit characters remain unchanged. Handles both Persian (U+06F0-U+06F9) and Arabic (U+0660-U+0669) digits.
+/// The function is total: it never panics, whatever Unicode the input contains.
 ///
 /// # Arguments
 ///
@@ -389,9 +637,9 @@ pub fn persian_or_arabic_digits_to_latin(s: &str) -> String {
         .map(|c| {
             let u = c as u32;
             if (0x0660..=0x0669).contains(&u) {
-                char::from_u32('0' as u32 + (u - 0x0660)).unwrap()
+                LATIN_DIGITS[(u - 0x0660) as usize]
             } else if (0x06F0..=0x06F9).contains(&u) {
-                char::from_u32('0' as u32 + (u - 0x06F0)).unwrap()
+                LATIN_DIGITS[(u - 0x06F0) as usize]
             } else {
                 c
             }
@@ -399,136 +647,6319 @@ pub fn persian_or_arabic_digits_to_latin(s: &str) -> String {
         .collect()
 }
 
-// Helper function to convert Julian Day Number (JDN) to Gregorian date.
-fn jdn_to_gregorian(jdn: i64) -> (i32, u32, u32) {
-    let a = jdn + 32044;
-    let b = (4 * a + 3) / 146097;
-    let c = a - (146097 * b) / 4;
-    let d = (4 * c + 3) / 1461;
-    let e = c - (1461 * d) / 4;
-    let m = (5 * e + 2) / 153;
-    let day = (e - (153 * m + 2) / 5 + 1) as u32;
-    let month = (m + 3 - 12 * (m / 10)) as u32;
-    let year = (100 * b + d - 4800 + (m / 10)) as i32;
-    (year, month, day)
-}
-
-// Helper function to convert Unix timestamp to Gregorian date.
-fn unix_to_gregorian(timestamp: i64) -> Option<(i32, u32, u32)> {
-    // Modify to handle negative timestamps
-    let days = timestamp / 86_400;
-    let jdn = 2_440_588 + days;
-    Some(jdn_to_gregorian(jdn))
-}
-
-// Helper function to convert Gregorian date to Unix timestamp.
-fn gregorian_to_unix(year: i32, month: u32, day: u32) -> Option<i64> {
-    let jdn = gregorian_to_jdn(year, month as i32, day as i32);
-    let days = jdn - 2_440_588;
-    Some(days * 86_400)
+/// Spells out a full Jalali date in Persian words, as used in formal documents and contracts.
+///
+/// The day is written as an ordinal, followed by the month name with "ماه" and the year in words
+/// (e.g., "ششم دی ماه یک هزار و چهارصد و چهار"). Returns an empty string if the month is outside 1-12
+/// or the day is zero.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year (e.g., 1404).
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// The date spelled out in Persian words.
+///
+/// # Examples
+///
+/// ```
+/// let words = jalali_rs::jalali_date_to_persian_words(1404, 10, 6);
+/// assert_eq!(words, "ششم دی ماه یک هزار و چهارصد و چهار");
+/// ```
+pub fn jalali_date_to_persian_words(year: i32, month: u32, day: u32) -> String {
+    if !(1..=12).contains(&month) || day == 0 {
+        return String::new();
+    }
+    format!(
+        "{} {} ماه {}",
+        persian_ordinal_words(day as i64),
+        JALALI_MONTH_NAMES[month as usize - 1],
+        persian_number_words(year as i64)
+    )
 }
 
-// Additional helper function for Julian Day Number conversion
-fn gregorian_to_jdn(year: i32, month: i32, day: i32) -> i64 {
-    let a = (14 - month) / 12;
-    let y = year + 4800 - a;
-    let m = month + 12 * a - 3;
+/// Parses a free-text Jalali date made of a numeric year, a Persian month name and a numeric day,
+/// in any order (e.g., "۶ دی ۱۴۰۴", "۱۴۰۴ دی ۶" or "دی ۶ ۱۴۰۴").
+///
+/// Tokens are separated by whitespace or commas and identified by type: the month name is looked up
+/// in the Persian month names, the number above 31 is taken as the year and the other as the day.
+/// An optional "ماه" after the month name is ignored. Handles Persian/Arabic digits and Arabic
+/// forms of the letters ی and ک. Returns `None` if the tokens can't be identified unambiguously.
+///
+/// # Arguments
+///
+/// * `input` - The date text.
+///
+/// # Returns
+///
+/// An `Option` containing (jalali_year, jalali_month, jalali_day) or `None`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::parse_jalali_natural("۶ دی ۱۴۰۴"), Some((1404, 10, 6)));
+/// assert_eq!(jalali_rs::parse_jalali_natural("۱۴۰۴ دی ۶"), Some((1404, 10, 6)));
+/// assert_eq!(jalali_rs::parse_jalali_natural("1404 10 6"), None);
+/// ```
+pub fn parse_jalali_natural(input: &str) -> Option<(i32, u32, u32)> {
+    let normalized: String = persian_or_arabic_digits_to_latin(input)
+        .chars()
+        .map(|c| match c {
+            'ي' => 'ی',
+            'ك' => 'ک',
+            _ => c,
+        })
+        .collect();
 
-    let jdn = day as i64
-        + ((153 * m + 2) / 5) as i64
-        + 365 * y as i64
-        + y as i64 / 4
-        - y as i64 / 100
-        + y as i64 / 400
-        - 32045;
+    let (mut month, mut numbers) = (None, Vec::new());
+    for token in normalized.split(|c: char| c.is_whitespace() || c == ',' || c == '،') {
+        if token.is_empty() || token == "ماه" {
+            continue;
+        }
+        if let Some(index) = JALALI_MONTH_NAMES.iter().position(|name| *name == token) {
+            if month.replace(index as u32 + 1).is_some() {
+                return None;
+            }
+        } else {
+            numbers.push(parse_number::<i32>(token)?);
+        }
+    }
 
-    jdn
+    let month = month?;
+    let (year, day) = match numbers[..] {
+        [a, b] if a > 31 && (1..=31).contains(&b) => (a, b),
+        [a, b] if b > 31 && (1..=31).contains(&a) => (b, a),
+        _ => return None,
+    };
+    Some((year, month, day as u32))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_gregorian_to_jalali() {
-        let (jy, jm, jd) = gregorian_to_jalali(2023, 12, 27);
-        assert_eq!(jy, 1402);
-        assert_eq!(jm, 10);
-        assert_eq!(jd, 6);
-
-        let (jy, jm, jd) = gregorian_to_jalali(2025, 12, 27);
-        assert_eq!(jy, 1404);
-        assert_eq!(jm, 10);
-        assert_eq!(jd, 6);
+/// Converts a Gregorian date to a Jalali date, normalizing out-of-range days.
+///
+/// Unlike `gregorian_to_jalali`, the day is counted from the first of the given month, so the
+/// output of naive date arithmetic is handled gracefully: day 0 is the last day of the previous
+/// month, negative days keep borrowing from earlier months, and days past the end of the month
+/// roll over into the following ones. Returns `None` if the month is outside 1-12.
+///
+/// # Arguments
+///
+/// * `gregorian_year` - The Gregorian year (e.g., 2025).
+/// * `gregorian_month` - The Gregorian month (1-12).
+/// * `gregorian_day` - The Gregorian day, relative to the start of the month.
+///
+/// # Returns
+///
+/// An `Option` containing (jalali_year, jalali_month, jalali_day) or `None`.
+///
+/// # Examples
+///
+/// ```
+/// // 2025-03-00 is 2025-02-28
+/// assert_eq!(jalali_rs::try_gregorian_to_jalali(2025, 3, 0), Some((1403, 12, 10)));
+/// assert_eq!(jalali_rs::try_gregorian_to_jalali(2025, 13, 1), None);
+/// ```
+pub fn try_gregorian_to_jalali(
+    gregorian_year: i32,
+    gregorian_month: u32,
+    gregorian_day: i32,
+) -> Option<(i32, u32, u32)> {
+    if !(1..=12).contains(&gregorian_month) {
+        return None;
     }
+    let jdn = gregorian_to_jdn(gregorian_year, gregorian_month as i32, 1) + gregorian_day as i64 - 1;
+    Some(jdn_to_jalali(jdn))
+}
 
-    #[test]
-    fn test_jalali_to_gregorian() {
-        let (gy, gm, gd) = jalali_to_gregorian(1402, 10, 6);
-        assert_eq!(gy, 2023);
-        assert_eq!(gm, 12);
-        assert_eq!(gd, 27);
-
-        let (gy, gm, gd) = jalali_to_gregorian(1404, 10, 6);
-        assert_eq!(gy, 2025);
-        assert_eq!(gm, 12);
-        assert_eq!(gd, 27);
+/// Counts how many times each weekday occurs in a Jalali month.
+///
+/// The counts are indexed from Saturday (Shanbe = 0) through Friday (Jomeh = 6), following the
+/// Iranian week. Returns all zeros if the month is outside 1-12.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year (e.g., 1404).
+/// * `month` - The Jalali month (1-12).
+///
+/// # Returns
+///
+/// An array with the number of Saturdays, Sundays, ..., Fridays in the month.
+///
+/// # Examples
+///
+/// ```
+/// // Dey 1404 has 30 days and starts on a Monday
+/// let counts = jalali_rs::jalali_month_weekday_counts(1404, 10);
+/// assert_eq!(counts, [4, 4, 5, 5, 4, 4, 4]);
+/// ```
+pub fn jalali_month_weekday_counts(year: i32, month: u32) -> [u32; 7] {
+    let mut counts = [0u32; 7];
+    if !(1..=12).contains(&month) {
+        return counts;
+    }
+    let days = jalali_month_length(year, month);
+    let first_weekday = jalali_weekday_index(year, month, 1);
+
+    // every weekday occurs once per full week, the leftover days start at the first weekday
+    for (index, count) in counts.iter_mut().enumerate() {
+        let offset = (index as u32 + 7 - first_weekday) % 7;
+        *count = days / 7 + u32::from(offset < days % 7);
+    }
+    counts
+}
+
+/// Returns the Gregorian start and end dates of a Jalali (Iranian fiscal) year.
+///
+/// The start is Farvardin 1 (Nowruz) and the end is the last day of Esfand, which is the 30th
+/// in leap years and the 29th otherwise.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year (e.g., 1404).
+///
+/// # Returns
+///
+/// A tuple containing the Gregorian (year, month, day) of the first and last day of the year.
+///
+/// # Examples
+///
+/// ```
+/// let (start, end) = jalali_rs::jalali_fiscal_year_gregorian_bounds(1404);
+/// assert_eq!(start, (2025, 3, 21));
+/// assert_eq!(end, (2026, 3, 20));
+/// ```
+pub fn jalali_fiscal_year_gregorian_bounds(year: i32) -> ((i32, u32, u32), (i32, u32, u32)) {
+    let start = jalali_to_gregorian(year, 1, 1);
+    let end = jalali_to_gregorian(year, 12, jalali_month_length(year, 12) as i32);
+    (start, end)
+}
+
+/// Parses the date part of a Jalali date-time string (e.g., "1404-10-06 12:30") and converts it to
+/// Gregorian string format.
+///
+/// Everything after the first whitespace is ignored, so exported timestamps can be passed in
+/// directly. Handles Persian/Arabic digits in input. Returns `None` if the date part is invalid.
+///
+/// # Arguments
+///
+/// * `date_str` - The date-time string.
+/// * `separator` - The separator character between the date parts (e.g., '-').
+///
+/// # Returns
+///
+/// An `Option` containing the Gregorian date string (e.g., "2025-12-27") or `None`.
+///
+/// # Examples
+///
+/// ```
+/// let result = jalali_rs::parse_jalali_datetime_string_to_gregorian_string("۱۴۰۴-۱۰-۰۶ ۱۲:۳۰", '-');
+/// assert_eq!(result, Some("2025-12-27".to_string()));
+/// ```
+pub fn parse_jalali_datetime_string_to_gregorian_string(date_str: &str, separator: char) -> Option<String> {
+    let date_part = date_str.split_whitespace().next()?;
+    parse_jalali_string_to_gregorian_string(date_part, separator)
+}
+
+/// Compares two Jalali dates chronologically, by their Julian Day Number.
+///
+/// Useful as a comparator for `sort_by` on collections of `(year, month, day)` tuples.
+///
+/// # Arguments
+///
+/// * `a` - The first Jalali date as (year, month, day).
+/// * `b` - The second Jalali date as (year, month, day).
+///
+/// # Returns
+///
+/// The `Ordering` of `a` relative to `b`.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(jalali_rs::jalali_cmp((1403, 12, 30), (1404, 1, 1)), Ordering::Less);
+///
+/// let mut dates = vec![(1404, 1, 1), (1403, 12, 30), (1403, 1, 1)];
+/// dates.sort_by(|a, b| jalali_rs::jalali_cmp(*a, *b));
+/// assert_eq!(dates, vec![(1403, 1, 1), (1403, 12, 30), (1404, 1, 1)]);
+/// ```
+pub fn jalali_cmp(a: (i32, u32, u32), b: (i32, u32, u32)) -> std::cmp::Ordering {
+    jalali_to_jdn(a.0, a.1, a.2).cmp(&jalali_to_jdn(b.0, b.1, b.2))
+}
+
+/// Parses a duration written with a Persian unit (e.g., "۲ هفته" or "۳ ماه") into a number of days.
+///
+/// Recognized units are روز (day), هفته (week = 7 days), ماه (month) and سال (year). Months and
+/// years vary in length, so they are approximated as 30 and 365 days respectively. Handles
+/// Persian/Arabic digits. Returns `None` for a missing number or an unrecognized unit.
+///
+/// # Arguments
+///
+/// * `input` - The duration text, a number followed by a unit.
+///
+/// # Returns
+///
+/// An `Option` containing the (approximate) number of days or `None`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::parse_persian_duration("۲ هفته"), Some(14));
+/// assert_eq!(jalali_rs::parse_persian_duration("۳ ماه"), Some(90));
+/// assert_eq!(jalali_rs::parse_persian_duration("۳ قرن"), None);
+/// ```
+pub fn parse_persian_duration(input: &str) -> Option<i64> {
+    let normalized = persian_or_arabic_digits_to_latin(input.trim());
+    let unit_start = normalized
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(normalized.len());
+    let amount = normalized[..unit_start].parse::<i64>().ok()?;
+    let days_per_unit = match normalized[unit_start..].trim() {
+        "روز" => 1,
+        "هفته" => 7,
+        "ماه" => 30,
+        "سال" => 365,
+        _ => return None,
+    };
+    amount.checked_mul(days_per_unit)
+}
+
+/// Writes a Jalali date in zero-padded `YYYY-MM-DD` form into any `std::fmt::Write` sink.
+///
+/// Produces the same text as the string helpers without allocating a `String`, which makes it
+/// suitable for hot logging paths that reuse a buffer.
+///
+/// # Arguments
+///
+/// * `out` - The destination to write into.
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// The `std::fmt::Result` of the write.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = String::new();
+/// jalali_rs::write_jalali(&mut buffer, 1404, 10, 6).unwrap();
+/// assert_eq!(buffer, "1404-10-06");
+/// ```
+pub fn write_jalali(out: &mut impl std::fmt::Write, year: i32, month: u32, day: u32) -> std::fmt::Result {
+    write!(out, "{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Returns the Gregorian date that was `n` Jalali months before today.
+///
+/// Today is taken from the system clock in UTC, as in `now_jalali`, and the work is done by
+/// `jalali_months_ago_gregorian_from`. Months are stepped on the
+/// Jalali calendar and the day is clamped to the length of the target month (e.g., 3 months
+/// before Khordad 31 is Esfand 29 or 30). A negative `n` counts months forward.
+///
+/// # Arguments
+///
+/// * `n` - The number of Jalali months to go back.
+///
+/// # Returns
+///
+/// An `Option` containing (gregorian_year, gregorian_month, gregorian_day) or `None`.
+///
+/// # Examples
+///
+/// ```
+/// if let Some((gy, gm, gd)) = jalali_rs::jalali_months_ago_gregorian(3) {
+///     println!("Three Persian months ago: {}-{:02}-{:02}", gy, gm, gd);
+/// }
+/// ```
+pub fn jalali_months_ago_gregorian(n: i32) -> Option<(i32, u32, u32)> {
+    jalali_months_ago_gregorian_from(n, current_unix_timestamp())
+}
+
+/// Returns the Gregorian date that was `n` Jalali months before an injected "now" timestamp.
+///
+/// The pure counterpart of `jalali_months_ago_gregorian`: today is `now_jalali_from(timestamp)`, and
+/// the months are stepped the same way.
+///
+/// # Arguments
+///
+/// * `n` - The number of Jalali months to go back.
+/// * `timestamp` - The current time as a Unix timestamp in seconds.
+///
+/// # Returns
+///
+/// An `Option` containing (gregorian_year, gregorian_month, gregorian_day) or `None`.
+///
+/// # Examples
+///
+/// ```
+/// // 1766806014 is 1404-10-06, and 1404-07-06 is 2025-09-28
+/// assert_eq!(jalali_rs::jalali_months_ago_gregorian_from(3, 1766806014), Some((2025, 9, 28)));
+/// ```
+pub fn jalali_months_ago_gregorian_from(n: i32, timestamp: i64) -> Option<(i32, u32, u32)> {
+    let (jy, jm, jd) = now_jalali_from(timestamp)?;
+    let (jy, jm, jd) = jalali_add_months_clamped(jy, jm, jd, -(n as i64));
+    Some(jalali_to_gregorian(jy, jm as usize, jd as i32))
+}
+
+/// Returns today's Jalali date from the system clock, in UTC.
+///
+/// This reads `SystemTime::now()` and delegates to `now_jalali_from`, which holds the actual logic
+/// and can be called with a fixed timestamp in tests. A system clock set before the Unix epoch gives
+/// the matching earlier date.
+///
+/// # Returns
+///
+/// An `Option` containing (jalali_year, jalali_month, jalali_day) or `None`.
+///
+/// # Examples
+///
+/// ```
+/// if let Some((jy, jm, jd)) = jalali_rs::now_jalali() {
+///     println!("Today: {}-{:02}-{:02}", jy, jm, jd);
+/// }
+/// ```
+pub fn now_jalali() -> Option<(i32, u32, u32)> {
+    now_jalali_from(current_unix_timestamp())
+}
+
+/// Returns the Jalali date for an injected "now" timestamp (seconds since 1970-01-01 UTC).
+///
+/// This is the pure counterpart of `now_jalali`: time-dependent code can take the timestamp as a
+/// parameter and stay deterministic under test. It converts the timestamp as `unix_to_jalali` does,
+/// so timestamps before 1970 floor to the previous UTC day and `None` is only returned if the year
+/// doesn't fit in an `i32`.
+///
+/// # Arguments
+///
+/// * `timestamp` - The current time as a Unix timestamp in seconds.
+///
+/// # Returns
+///
+/// An `Option` containing (jalali_year, jalali_month, jalali_day) or `None`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::now_jalali_from(1766806014), Some((1404, 10, 6)));
+/// ```
+pub fn now_jalali_from(timestamp: i64) -> Option<(i32, u32, u32)> {
+    unix_to_jalali(timestamp)
+}
+
+/// Returns the first and last day of a Persian season in a Jalali year.
+///
+/// Seasons are numbered 1 to 4: Bahar (Farvardin 1 - Khordad 31), Tabestan (Tir 1 - Shahrivar 31),
+/// Paiz (Mehr 1 - Azar 30) and Zemestan (Dey 1 - Esfand 29, or Esfand 30 in leap years).
+/// Returns `None` for a season outside 1-4, as `jalali_quarter_start` and `jalali_quarter_end` do for
+/// quarters.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year (e.g., 1404).
+/// * `season` - The season number (1-4).
+///
+/// # Returns
+///
+/// An `Option` containing the Jalali (year, month, day) of the season's first and last day, or `None`.
+///
+/// # Examples
+///
+/// ```
+/// let bounds = jalali_rs::jalali_season_bounds(1404, 1);
+/// assert_eq!(bounds, Some(((1404, 1, 1), (1404, 3, 31))));
+/// ```
+pub fn jalali_season_bounds(year: i32, season: u32) -> Option<(JalaliTuple, JalaliTuple)> {
+    if !(1..=4).contains(&season) {
+        return None;
+    }
+    let first_month = (season - 1) * 3 + 1;
+    let last_month = first_month + 2;
+    Some((
+        (year, first_month, 1),
+        (year, last_month, jalali_month_length(year, last_month)),
+    ))
+}
+
+/// Converts a Gregorian date given as a `(year, month, day)` tuple to a Jalali date.
+///
+/// A tuple-in, tuple-out wrapper over `gregorian_to_jalali` for `map` chains over date tuples.
+///
+/// # Arguments
+///
+/// * `gregorian` - The Gregorian date as (year, month, day).
+///
+/// # Returns
+///
+/// A tuple containing (jalali_year, jalali_month, jalali_day).
+///
+/// # Examples
+///
+/// ```
+/// let dates = vec![(2025, 12, 27), (2025, 3, 21)];
+/// let jalali: Vec<_> = dates.into_iter().map(jalali_rs::gregorian_tuple_to_jalali).collect();
+/// assert_eq!(jalali, vec![(1404, 10, 6), (1404, 1, 1)]);
+/// ```
+pub fn gregorian_tuple_to_jalali(gregorian: (i32, u32, u32)) -> (i32, u32, u32) {
+    gregorian_to_jalali(gregorian.0, gregorian.1 as usize, gregorian.2 as i32)
+}
+
+/// Converts a Jalali date given as a `(year, month, day)` tuple to a Gregorian date.
+///
+/// A tuple-in, tuple-out wrapper over `jalali_to_gregorian` for `map` chains over date tuples.
+///
+/// # Arguments
+///
+/// * `jalali` - The Jalali date as (year, month, day).
+///
+/// # Returns
+///
+/// A tuple containing (gregorian_year, gregorian_month, gregorian_day).
+///
+/// # Examples
+///
+/// ```
+/// let dates = vec![(1404, 10, 6), (1404, 1, 1)];
+/// let gregorian: Vec<_> = dates.into_iter().map(jalali_rs::jalali_tuple_to_gregorian).collect();
+/// assert_eq!(gregorian, vec![(2025, 12, 27), (2025, 3, 21)]);
+/// ```
+pub fn jalali_tuple_to_gregorian(jalali: (i32, u32, u32)) -> (i32, u32, u32) {
+    jalali_to_gregorian(jalali.0, jalali.1 as usize, jalali.2 as i32)
+}
+
+/// Checks whether two Jalali dates fall in the same month of the same year.
+///
+/// # Arguments
+///
+/// * `a` - The first Jalali date as (year, month, day).
+/// * `b` - The second Jalali date as (year, month, day).
+///
+/// # Returns
+///
+/// `true` if both the year and the month match.
+///
+/// # Examples
+///
+/// ```
+/// assert!(jalali_rs::jalali_same_month((1404, 10, 1), (1404, 10, 30)));
+/// assert!(!jalali_rs::jalali_same_month((1403, 10, 1), (1404, 10, 1)));
+/// ```
+pub fn jalali_same_month(a: (i32, u32, u32), b: (i32, u32, u32)) -> bool {
+    a.0 == b.0 && a.1 == b.1
+}
+
+/// Checks whether two Jalali dates fall in the same year.
+///
+/// # Arguments
+///
+/// * `a` - The first Jalali date as (year, month, day).
+/// * `b` - The second Jalali date as (year, month, day).
+///
+/// # Returns
+///
+/// `true` if the years match.
+///
+/// # Examples
+///
+/// ```
+/// assert!(jalali_rs::jalali_same_year((1404, 1, 1), (1404, 12, 29)));
+/// assert!(!jalali_rs::jalali_same_year((1403, 12, 30), (1404, 1, 1)));
+/// ```
+pub fn jalali_same_year(a: (i32, u32, u32), b: (i32, u32, u32)) -> bool {
+    a.0 == b.0
+}
+
+/// Converts a Jalali date to a Hijri (lunar Islamic) date.
+///
+/// Uses the arithmetic (tabular) Islamic calendar with the civil epoch of 16 July 622 (Julian).
+/// Official Hijri dates are set by moon sighting, so they can differ from the tabular calendar by a
+/// day or two. Returns `None` for dates before 1 Muharram 1.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year (e.g., 1404).
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// An `Option` containing (hijri_year, hijri_month, hijri_day) or `None`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_to_hijri(1404, 4, 6), Some((1447, 1, 1)));
+/// ```
+pub fn jalali_to_hijri(year: i32, month: u32, day: u32) -> Option<(i32, u32, u32)> {
+    jdn_to_hijri(jalali_to_jdn(year, month, day))
+}
+
+/// Converts a Jalali date to a Hijri date string with the Arabic month name (e.g., "1 محرم 1447").
+///
+/// Built on `jalali_to_hijri`, so the same tabular-calendar caveat applies. Returns an empty string
+/// for dates before 1 Muharram 1.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year (e.g., 1404).
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// The Hijri date formatted as "day month_name year".
+///
+/// # Examples
+///
+/// ```
+/// let hijri = jalali_rs::jalali_to_hijri_string(1404, 4, 6);
+/// assert_eq!(hijri, "1 محرم 1447");
+/// ```
+pub fn jalali_to_hijri_string(year: i32, month: u32, day: u32) -> String {
+    match jalali_to_hijri(year, month, day) {
+        Some((hy, hm, hd)) => format!("{} {} {}", hd, HIJRI_MONTH_NAMES[hm as usize - 1], hy),
+        None => String::new(),
+    }
+}
+
+/// Checks whether a Jalali year begins a new 33-year leap cycle.
+///
+/// The crate's arithmetic places 8 leap years in each 33-year cycle, and the cycles are anchored so
+/// that 1408 starts one; the starts are therefore every year congruent to 1408 modulo 33
+/// (..., 1342, 1375, 1408, 1441, ...). The first year of every cycle is a leap year.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+///
+/// # Returns
+///
+/// `true` if the year is the first year of a leap cycle.
+///
+/// # Examples
+///
+/// ```
+/// assert!(jalali_rs::jalali_is_cycle_start(1408));
+/// assert!(!jalali_rs::jalali_is_cycle_start(1404));
+/// ```
+pub fn jalali_is_cycle_start(year: i32) -> bool {
+    (year as i64 + 1595).rem_euclid(33) == 0
+}
+
+/// Display data for a single Jalali date, as returned by `describe_jalali`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateDescription {
+    /// The Jalali date as (year, month, day).
+    pub jalali: (i32, u32, u32),
+    /// The equivalent Gregorian date as (year, month, day).
+    pub gregorian: (i32, u32, u32),
+    /// The Persian weekday name (e.g., "شنبه").
+    pub weekday_name: &'static str,
+    /// The Persian month name (e.g., "دی").
+    pub month_name: &'static str,
+    /// Whether the date is a Friday, the Iranian weekend.
+    pub is_weekend: bool,
+    /// Whether the date is a fixed solar-calendar public holiday (e.g., Nowruz).
+    pub is_holiday: bool,
+}
+
+/// Collects the data a calendar widget needs for one Jalali date in a single call.
+///
+/// Only the fixed solar-calendar holidays are detected (Nowruz, Farvardin 12 and 13, Khordad 14 and 15,
+/// Bahman 22 and Esfand 29); holidays that follow the lunar calendar move every year and aren't covered.
+/// Returns `None` if the date doesn't exist.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year (e.g., 1404).
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// An `Option` containing the `DateDescription` or `None`.
+///
+/// # Examples
+///
+/// ```
+/// let description = jalali_rs::describe_jalali(1404, 1, 1).unwrap();
+/// assert_eq!(description.gregorian, (2025, 3, 21));
+/// assert_eq!(description.weekday_name, "جمعه");
+/// assert_eq!(description.month_name, "فروردین");
+/// assert!(description.is_weekend && description.is_holiday);
+/// ```
+pub fn describe_jalali(year: i32, month: u32, day: u32) -> Option<DateDescription> {
+    if !is_valid_jalali_date(year, month, day) {
+        return None;
+    }
+    let weekday = jalali_weekday_index(year, month, day);
+    Some(DateDescription {
+        jalali: (year, month, day),
+        gregorian: jalali_to_gregorian(year, month as usize, day as i32),
+        weekday_name: WEEKDAY_NAMES[weekday as usize],
+        month_name: JALALI_MONTH_NAMES[month as usize - 1],
+        is_weekend: weekday == 6,
+        is_holiday: jalali_fixed_holiday_name(month, day).is_some(),
+    })
+}
+
+/// Finds every Jalali date written as `YYYY-MM-DD` inside a larger text.
+///
+/// The text is digit-normalized first, so Persian and Arabic digits are recognized. A date is a
+/// four-digit year, a one- or two-digit month and a one- or two-digit day, separated by `-`, `/` or
+/// `.` and not directly preceded or followed by another digit. Matches that aren't existing Jalali
+/// dates (e.g., 1404-07-31) are skipped.
+///
+/// # Arguments
+///
+/// * `text` - The text to scan.
+///
+/// # Returns
+///
+/// A vector of (jalali_year, jalali_month, jalali_day) in the order they appear.
+///
+/// # Examples
+///
+/// ```
+/// let dates = jalali_rs::find_jalali_dates("جلسه از ۱۴۰۴/۱۰/۰۶ به 1404-10-13 منتقل شد");
+/// assert_eq!(dates, vec![(1404, 10, 6), (1404, 10, 13)]);
+/// ```
+pub fn find_jalali_dates(text: &str) -> Vec<(i32, u32, u32)> {
+    let chars: Vec<char> = persian_or_arabic_digits_to_latin(text).chars().collect();
+    let mut dates = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        match match_jalali_date_at(&chars, index) {
+            Some((date, end)) => {
+                dates.push(date);
+                index = end;
+            }
+            None => index += 1,
+        }
+    }
+    dates
+}
+
+/// Returns the Gregorian date of the last Friday in a Jalali month.
+///
+/// Useful for rules like "payday is the last Friday of the Persian month" in systems that store
+/// Gregorian dates. Returns `None` if the month is outside 1-12.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year (e.g., 1404).
+/// * `month` - The Jalali month (1-12).
+///
+/// # Returns
+///
+/// An `Option` containing (gregorian_year, gregorian_month, gregorian_day) or `None`.
+///
+/// # Examples
+///
+/// ```
+/// // the last Friday of Dey 1404 is Dey 26
+/// assert_eq!(jalali_rs::jalali_last_friday_gregorian(1404, 10), Some((2026, 1, 16)));
+/// ```
+pub fn jalali_last_friday_gregorian(year: i32, month: u32) -> Option<(i32, u32, u32)> {
+    if !(1..=12).contains(&month) {
+        return None;
+    }
+    let last_day = jalali_month_length(year, month);
+    // Friday is 6 in the Saturday-based numbering, so step back past the later weekdays
+    let days_after_friday = (jalali_weekday_index(year, month, last_day) + 1) % 7;
+    let friday = last_day - days_after_friday;
+    Some(jalali_to_gregorian(year, month as usize, friday as i32))
+}
+
+/// Formats the Persian week containing a Jalali date as a week string like "1404-W42".
+///
+/// Weeks start on Saturday. Week 1 is the week containing Farvardin 1 (Nowruz), even when it is a
+/// partial week, and each following Saturday starts the next week.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year (e.g., 1404).
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// The week string in `YYYY-Www` form.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_to_week_string(1404, 10, 6), "1404-W42");
+/// assert_eq!(jalali_rs::jalali_to_week_string(1404, 1, 1), "1404-W01");
+/// ```
+pub fn jalali_to_week_string(year: i32, month: u32, day: u32) -> String {
+    format!("{:04}-W{:02}", year, jalali_week_number(year, month, day))
+}
+
+/// Parses a Persian week string like "1404-W42" and returns the Saturday that starts the week.
+///
+/// Uses the same convention as `jalali_to_week_string`. Since week 1 contains Nowruz, its Saturday
+/// can fall at the end of the previous Jalali year. Handles Persian/Arabic digits. Returns `None` for
+/// invalid formats or a week number the year doesn't have.
+///
+/// # Arguments
+///
+/// * `week_str` - The week string.
+///
+/// # Returns
+///
+/// An `Option` containing the (jalali_year, jalali_month, jalali_day) of the week's Saturday or `None`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::parse_jalali_week_string("1404-W42"), Some((1404, 10, 6)));
+/// assert_eq!(jalali_rs::parse_jalali_week_string("1404-W60"), None);
+/// ```
+pub fn parse_jalali_week_string(week_str: &str) -> Option<(i32, u32, u32)> {
+    let normalized = persian_or_arabic_digits_to_latin(week_str);
+    let (year, week) = normalized.split_once("-W")?;
+    let year = parse_number::<i32>(year)?;
+    let week = week.parse::<u32>().ok()?;
+    let last_week = jalali_week_number(year, 12, jalali_month_length(year, 12));
+    if !(1..=last_week).contains(&week) {
+        return None;
+    }
+    let first_saturday = jalali_to_jdn(year, 1, 1) - jalali_weekday_index(year, 1, 1) as i64;
+    Some(jdn_to_jalali(first_saturday + (week as i64 - 1) * 7))
+}
+
+/// Counts the working days (every day except Friday) in an inclusive range of Jalali dates.
+///
+/// Returns 0 if `to` is before `from`. Use `jalali_working_days_between_with` to also deduct holidays.
+///
+/// # Arguments
+///
+/// * `from` - The first Jalali date of the range as (year, month, day).
+/// * `to` - The last Jalali date of the range as (year, month, day).
+///
+/// # Returns
+///
+/// The number of non-Friday days from `from` to `to`, both included.
+///
+/// # Examples
+///
+/// ```
+/// // two full weeks contain two Fridays
+/// assert_eq!(jalali_rs::jalali_working_days_between((1404, 10, 6), (1404, 10, 19)), 12);
+/// ```
+pub fn jalali_working_days_between(from: (i32, u32, u32), to: (i32, u32, u32)) -> i64 {
+    let start = jalali_to_jdn(from.0, from.1, from.2);
+    let end = jalali_to_jdn(to.0, to.1, to.2);
+    if end < start {
+        return 0;
+    }
+    // Fridays are the days with (jdn + 2) % 7 == 6, i.e. jdn % 7 == 4; count them up to each end
+    let fridays_through = |jdn: i64| (jdn - 4).div_euclid(7);
+    (end - start + 1) - (fridays_through(end) - fridays_through(start - 1))
+}
+
+/// Counts the working days in an inclusive range of Jalali dates, deducting Fridays and holidays.
+///
+/// Every non-Friday day in the range is passed to `is_holiday`, and the days it returns `true` for
+/// are deducted as well. Returns 0 if `to` is before `from`.
+///
+/// # Arguments
+///
+/// * `from` - The first Jalali date of the range as (year, month, day).
+/// * `to` - The last Jalali date of the range as (year, month, day).
+/// * `is_holiday` - A predicate marking additional non-working days.
+///
+/// # Returns
+///
+/// The number of working days from `from` to `to`, both included.
+///
+/// # Examples
+///
+/// ```
+/// // Farvardin 1-4 (Nowruz) and 12-13 are holidays
+/// let holidays = |(_, month, day): (i32, u32, u32)| month == 1 && matches!(day, 1..=4 | 12 | 13);
+/// let working = jalali_rs::jalali_working_days_between_with((1404, 1, 1), (1404, 1, 14), holidays);
+/// assert_eq!(working, 7);
+/// ```
+pub fn jalali_working_days_between_with(
+    from: (i32, u32, u32),
+    to: (i32, u32, u32),
+    is_holiday: impl Fn((i32, u32, u32)) -> bool,
+) -> i64 {
+    let start = jalali_to_jdn(from.0, from.1, from.2);
+    let end = jalali_to_jdn(to.0, to.1, to.2);
+    (start..=end)
+        .filter(|jdn| (jdn + 2).rem_euclid(7) != 6)
+        .filter(|&jdn| !is_holiday(jdn_to_jalali(jdn)))
+        .count() as i64
+}
+
+/// Returns the first year of the decade a Jalali year belongs to.
+///
+/// Decades are grouped by the tens digit, so 1400 through 1409 form the decade 1400. The year is rounded
+/// toward negative infinity, which keeps every decade ten years long before year 0 too (-5 → -10). The few
+/// years nearest `i32::MIN`, whose decade start isn't representable, saturate to `i32::MIN`.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+///
+/// # Returns
+///
+/// The year starting the decade.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_decade(1404), 1400);
+/// assert_eq!(jalali_rs::jalali_decade(-5), -10);
+/// ```
+pub fn jalali_decade(year: i32) -> i32 {
+    year.saturating_sub(year.rem_euclid(10))
+}
+
+/// Returns the hundreds group of a Jalali year, as in "the 1400s" → 14.
+///
+/// Centuries are grouped by the leading digits, so 1400 through 1499 give 14. The year is rounded toward
+/// negative infinity, so years -100 through -1 give -1.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+///
+/// # Returns
+///
+/// The year divided by 100, rounded down.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_century(1404), 14);
+/// assert_eq!(jalali_rs::jalali_century(-1), -1);
+/// ```
+pub fn jalali_century(year: i32) -> i32 {
+    year.div_euclid(100)
+}
+
+/// A rough position within a month, as used in phrases like "اوایل دی" (early Dey).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MonthPhase {
+    /// Days 1-10 ("اوایل").
+    Early,
+    /// Days 11-20 ("اواسط").
+    Mid,
+    /// Days 21 to the end of the month ("اواخر").
+    Late,
+}
+
+/// Places a day of a Jalali month into the early, middle or late third of the month.
+///
+/// The cutoffs are fixed at 10 and 20 so the same day always gets the same label: days 1-10 are early,
+/// 11-20 are mid and 21 onwards are late, which covers day 31 of the first six months as well. Day 0 is
+/// treated as early.
+///
+/// # Arguments
+///
+/// * `day` - The day of the month.
+///
+/// # Returns
+///
+/// The `MonthPhase` the day falls in.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::MonthPhase;
+///
+/// assert_eq!(jalali_rs::jalali_month_phase(6), MonthPhase::Early);
+/// assert_eq!(jalali_rs::jalali_month_phase(31), MonthPhase::Late);
+/// ```
+pub fn jalali_month_phase(day: u32) -> MonthPhase {
+    match day {
+        0..=10 => MonthPhase::Early,
+        11..=20 => MonthPhase::Mid,
+        _ => MonthPhase::Late,
+    }
+}
+
+/// Returns the 1-based index of a Jalali date within its Persian season.
+///
+/// The seasons are those of `jalali_season_bounds`, so Farvardin 1, Tir 1, Mehr 1 and Dey 1 are each day 1.
+/// Summer and spring run to 93 days, autumn to 90 and winter to 89 (90 in leap years). Returns 0 if the
+/// date doesn't exist, including a day past the end of its month.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// The day of the season, starting at 1.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_day_of_season(1404, 4, 1), 1);
+/// assert_eq!(jalali_rs::jalali_day_of_season(1404, 10, 6), 6);
+/// ```
+pub fn jalali_day_of_season(year: i32, month: u32, day: u32) -> u32 {
+    if !is_valid_jalali_date(year, month, day) {
+        return 0;
+    }
+    let (first, _) = jalali_season_bounds(year, (month - 1) / 3 + 1).expect("a valid month is in a season");
+    (jalali_to_jdn(year, month, day) - jalali_to_jdn(first.0, first.1, first.2) + 1) as u32
+}
+
+/// A precomputed table of Nowruz days that speeds up conversions within a fixed window of Jalali years.
+///
+/// Build it once with `JalaliConversionTable::new` for the years an application deals with, then convert
+/// with `to_gregorian` and `from_gregorian`. Each lookup is a constant-time index into the table, and the
+/// results match `jalali_to_gregorian` and `gregorian_to_jalali`. Dates outside the window give `None`, so
+/// callers can fall back to the scalar functions. The table stores one entry per year, so keep the window
+/// to the range actually needed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JalaliConversionTable {
+    start_year: i32,
+    // the JDN of Farvardin 1 of every year in the window, plus the day after the window ends
+    nowruz_jdns: Vec<i64>,
+}
+
+impl JalaliConversionTable {
+    /// Precomputes the table for the Jalali years `start_year` through `end_year`, both included.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_year` - The first Jalali year in the window.
+    /// * `end_year` - The last Jalali year in the window.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the table, or `None` if `end_year` is before `start_year`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let table = jalali_rs::JalaliConversionTable::new(1384, 1424).unwrap();
+    /// assert_eq!(table.to_gregorian(1404, 10, 6), Some((2025, 12, 27)));
+    /// assert_eq!(table.from_gregorian(2025, 12, 27), Some((1404, 10, 6)));
+    /// ```
+    pub fn new(start_year: i32, end_year: i32) -> Option<Self> {
+        if end_year < start_year {
+            return None;
+        }
+        let mut nowruz_jdns: Vec<i64> =
+            (start_year..=end_year).map(|year| jalali_to_jdn(year, 1, 1)).collect();
+        nowruz_jdns.push(jalali_to_jdn(end_year, 12, jalali_month_length(end_year, 12)) + 1);
+        Some(JalaliConversionTable { start_year, nowruz_jdns })
+    }
+
+    /// Converts a Jalali date in the table's window to a Gregorian date.
+    ///
+    /// # Arguments
+    ///
+    /// * `jalali_year` - The Jalali year.
+    /// * `jalali_month` - The Jalali month (1-12).
+    /// * `jalali_day` - The Jalali day (1-31).
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing (gregorian_year, gregorian_month, gregorian_day), or `None` if the year is
+    /// outside the window or the date doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let table = jalali_rs::JalaliConversionTable::new(1400, 1410).unwrap();
+    /// assert_eq!(table.to_gregorian(1403, 12, 30), Some((2025, 3, 20)));
+    /// assert_eq!(table.to_gregorian(1404, 12, 30), None);
+    /// assert_eq!(table.to_gregorian(1420, 1, 1), None);
+    /// ```
+    pub fn to_gregorian(
+        &self,
+        jalali_year: i32,
+        jalali_month: u32,
+        jalali_day: u32,
+    ) -> Option<(i32, u32, u32)> {
+        let index = usize::try_from(jalali_year as i64 - self.start_year as i64).ok()?;
+        let nowruz = *self.nowruz_jdns.get(index)?;
+        let next_nowruz = *self.nowruz_jdns.get(index + 1)?;
+        if !(1..=12).contains(&jalali_month) || jalali_day == 0 {
+            return None;
+        }
+        let day_of_year = if jalali_month <= 6 {
+            (jalali_month - 1) * 31 + jalali_day - 1
+        } else {
+            186 + (jalali_month - 7) * 30 + jalali_day - 1
+        };
+        let month_days = if jalali_month <= 6 { 31 } else { 30 };
+        let jdn = nowruz + day_of_year as i64;
+        if jalali_day > month_days || jdn >= next_nowruz {
+            return None;
+        }
+        Some(days_to_gregorian(jdn - epoch::GREGORIAN_EPOCH_JDN + 366))
+    }
+
+    /// Converts a Gregorian date to a Jalali date in the table's window.
+    ///
+    /// # Arguments
+    ///
+    /// * `gregorian_year` - The Gregorian year.
+    /// * `gregorian_month` - The Gregorian month (1-12).
+    /// * `gregorian_day` - The Gregorian day (1-31).
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing (jalali_year, jalali_month, jalali_day), or `None` if the date doesn't exist
+    /// or falls outside the window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let table = jalali_rs::JalaliConversionTable::new(1400, 1410).unwrap();
+    /// assert_eq!(table.from_gregorian(2025, 3, 21), Some((1404, 1, 1)));
+    /// assert_eq!(table.from_gregorian(2025, 2, 30), None);
+    /// assert_eq!(table.from_gregorian(2000, 1, 1), None);
+    /// ```
+    pub fn from_gregorian(
+        &self,
+        gregorian_year: i32,
+        gregorian_month: u32,
+        gregorian_day: u32,
+    ) -> Option<(i32, u32, u32)> {
+        if !is_valid_gregorian_date(gregorian_year, gregorian_month, gregorian_day) {
+            return None;
+        }
+        let jdn = gregorian_to_jdn(gregorian_year, gregorian_month as i32, gregorian_day as i32);
+        let first = self.nowruz_jdns[0];
+        let last = *self.nowruz_jdns.last()?;
+        if jdn < first || jdn >= last {
+            return None;
+        }
+        // estimate the year from the mean year length (12053 days per 33 years), then correct by one
+        let mut index = ((jdn - first) * 33 / 12053) as usize;
+        index = index.min(self.nowruz_jdns.len() - 2);
+        while self.nowruz_jdns[index] > jdn {
+            index -= 1;
+        }
+        while self.nowruz_jdns[index + 1] <= jdn {
+            index += 1;
+        }
+        let day_of_year = (jdn - self.nowruz_jdns[index]) as u32;
+        let (month, day) = if day_of_year < 186 {
+            (day_of_year / 31 + 1, day_of_year % 31 + 1)
+        } else {
+            ((day_of_year - 186) / 30 + 7, (day_of_year - 186) % 30 + 1)
+        };
+        Some(((self.start_year as i64 + index as i64) as i32, month, day))
+    }
+}
+
+/// Returns the Gregorian date of the Nowruz (Farvardin 1) that falls in a Gregorian year.
+///
+/// Every Gregorian year in the historical range contains exactly one Nowruz, on March 20, 21 or 22: the
+/// first day of Jalali year `gregorian_year - 621`. The mean lengths of the two calendar years differ by
+/// about a day per twelve thousand years, so hundreds of thousands of years out Nowruz drifts away from
+/// March while still being that Jalali year's first day.
+///
+/// # Arguments
+///
+/// * `gregorian_year` - The Gregorian year (e.g., 2025).
+///
+/// # Returns
+///
+/// The Gregorian (year, month, day) of Nowruz in that year.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::nowruz_in_gregorian_year(2025), (2025, 3, 21));
+/// assert_eq!(jalali_rs::nowruz_in_gregorian_year(2024), (2024, 3, 20));
+/// ```
+pub fn nowruz_in_gregorian_year(gregorian_year: i32) -> (i32, u32, u32) {
+    jalali_to_gregorian(gregorian_year.saturating_sub(621), 1, 1)
+}
+
+/// Returns how far through its Jalali year a date is, as a fraction from 0.0 to just under 1.0.
+///
+/// The fraction is the number of whole days elapsed since Farvardin 1 divided by the length of the year
+/// (365 or 366 days), so Farvardin 1 gives exactly 0.0 and the last day of Esfand gives 364/365 (or
+/// 365/366); 1.0 is reached at the next Nowruz. Returns `None` if the date doesn't exist.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// An `Option` containing the elapsed fraction of the year, or `None`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_year_fraction(1404, 1, 1), Some(0.0));
+/// assert_eq!(jalali_rs::jalali_year_fraction(1404, 12, 29), Some(364.0 / 365.0));
+/// ```
+pub fn jalali_year_fraction(year: i32, month: u32, day: u32) -> Option<f64> {
+    if !is_valid_jalali_date(year, month, day) {
+        return None;
+    }
+    let nowruz = jalali_to_jdn(year, 1, 1);
+    let elapsed = jalali_to_jdn(year, month, day) - nowruz;
+    let year_length = jalali_to_jdn(year, 12, jalali_month_length(year, 12)) + 1 - nowruz;
+    Some(elapsed as f64 / year_length as f64)
+}
+
+/// Returns how many days ago a Jalali date was, counted against today's date in UTC.
+///
+/// This reads the system clock and delegates to `jalali_days_since_from`. Today gives 0 and yesterday 1.
+/// Returns `None` if the date is in the future or doesn't exist.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// An `Option` containing the number of days since the date, or `None`.
+///
+/// # Examples
+///
+/// ```
+/// if let Some(days) = jalali_rs::jalali_days_since(1404, 1, 1) {
+///     println!("Nowruz 1404 was {} days ago", days);
+/// }
+/// ```
+pub fn jalali_days_since(year: i32, month: u32, day: u32) -> Option<i64> {
+    jalali_days_since_from(year, month, day, current_unix_timestamp())
+}
+
+/// Returns how many days ago a Jalali date was, relative to an injected "now" timestamp.
+///
+/// The timestamp is taken as UTC, so "today" is the Jalali date of `now_jalali_from(timestamp)`. Returns
+/// `None` if the date is after that day or doesn't exist.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+/// * `timestamp` - The current time as a Unix timestamp in seconds.
+///
+/// # Returns
+///
+/// An `Option` containing the number of days since the date, or `None`.
+///
+/// # Examples
+///
+/// ```
+/// // 1766806014 is 1404-10-06
+/// assert_eq!(jalali_rs::jalali_days_since_from(1404, 9, 30, 1766806014), Some(6));
+/// assert_eq!(jalali_rs::jalali_days_since_from(1404, 10, 7, 1766806014), None);
+/// ```
+pub fn jalali_days_since_from(year: i32, month: u32, day: u32, timestamp: i64) -> Option<i64> {
+    if !is_valid_jalali_date(year, month, day) {
+        return None;
+    }
+    let (today_year, today_month, today_day) = now_jalali_from(timestamp)?;
+    let days = jalali_to_jdn(today_year, today_month, today_day) - jalali_to_jdn(year, month, day);
+    (days >= 0).then_some(days)
+}
+
+/// Converts a date in the Julian calendar to a Julian Day Number (JDN).
+///
+/// The Julian calendar was the civil calendar in Europe until the Gregorian reform of October 1582 and
+/// is the one historical sources before then use. The crate's Gregorian functions are proleptic: they
+/// extend the Gregorian rules backwards, so for earlier dates the two calendars disagree (by 10 days in
+/// 1582 and 3 days in 622). Years use astronomical numbering, where year 0 is 1 BCE.
+///
+/// # Arguments
+///
+/// * `year` - The Julian calendar year.
+/// * `month` - The month (1-12).
+/// * `day` - The day of the month.
+///
+/// # Returns
+///
+/// The Julian Day Number of the date.
+///
+/// # Examples
+///
+/// ```
+/// // the day the Gregorian calendar was adopted: 1582-10-05 Julian is 1582-10-15 Gregorian
+/// assert_eq!(jalali_rs::julian_to_jdn(1582, 10, 5), 2299161);
+/// ```
+pub fn julian_to_jdn(year: i32, month: u32, day: u32) -> i64 {
+    let a = (14 - month as i64) / 12;
+    let y = year as i64 + 4800 - a;
+    let m = month as i64 + 12 * a - 3;
+    day as i64 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - 32083
+}
+
+/// Converts a Julian Day Number (JDN) to a date in the Julian calendar.
+///
+/// This is the inverse of `julian_to_jdn`; see there for how the Julian calendar relates to the
+/// proleptic Gregorian calendar used elsewhere in the crate.
+///
+/// # Arguments
+///
+/// * `jdn` - The Julian Day Number.
+///
+/// # Returns
+///
+/// A tuple containing the Julian calendar (year, month, day).
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jdn_to_julian(2299161), (1582, 10, 5));
+/// ```
+pub fn jdn_to_julian(jdn: i64) -> (i32, u32, u32) {
+    let c = jdn + 32082;
+    let d = (4 * c + 3).div_euclid(1461);
+    let e = c - (1461 * d).div_euclid(4);
+    let m = (5 * e + 2) / 153;
+    let day = (e - (153 * m + 2) / 5 + 1) as u32;
+    let month = (m + 3 - 12 * (m / 10)) as u32;
+    let year = (d - 4800 + m / 10) as i32;
+    (year, month, day)
+}
+
+/// Converts a Jalali date to the Julian calendar.
+///
+/// Use this instead of `jalali_to_gregorian` for dates before October 1582, where historical sources
+/// use the Julian calendar rather than the proleptic Gregorian one.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// A tuple containing the Julian calendar (year, month, day).
+///
+/// # Examples
+///
+/// ```
+/// // Farvardin 1, year 1 is 622-03-21 proleptic Gregorian but 622-03-18 Julian
+/// assert_eq!(jalali_rs::jalali_to_julian(1, 1, 1), (622, 3, 18));
+/// assert_eq!(jalali_rs::jalali_to_gregorian(1, 1, 1), (622, 3, 21));
+/// ```
+pub fn jalali_to_julian(year: i32, month: u32, day: u32) -> (i32, u32, u32) {
+    jdn_to_julian(jalali_to_jdn(year, month, day))
+}
+
+/// Converts a date in the Julian calendar to a Jalali date.
+///
+/// Use this instead of `gregorian_to_jalali` for dates taken from sources before October 1582.
+///
+/// # Arguments
+///
+/// * `year` - The Julian calendar year.
+/// * `month` - The month (1-12).
+/// * `day` - The day of the month.
+///
+/// # Returns
+///
+/// A tuple containing the Jalali (year, month, day).
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::julian_to_jalali(622, 3, 18), (1, 1, 1));
+/// ```
+pub fn julian_to_jalali(year: i32, month: u32, day: u32) -> (i32, u32, u32) {
+    jdn_to_jalali(julian_to_jdn(year, month, day))
+}
+
+/// Lists the fixed public holidays in an inclusive range of Jalali dates, with their Persian names.
+///
+/// Only holidays fixed in the solar calendar are included (Nowruz, Farvardin 12 and 13, Khordad 14 and 15,
+/// Bahman 22 and Esfand 29); religious holidays follow the lunar calendar, move every year and aren't
+/// listed. Each of the four Nowruz days is its own entry. Returns an empty list if `to` is before `from`.
+///
+/// # Arguments
+///
+/// * `from` - The first Jalali date of the range as (year, month, day).
+/// * `to` - The last Jalali date of the range as (year, month, day).
+///
+/// # Returns
+///
+/// A vector of the holidays in date order, each as the Jalali (year, month, day) and its name.
+///
+/// # Examples
+///
+/// ```
+/// let holidays = jalali_rs::jalali_holidays_in_range((1404, 1, 10), (1404, 1, 20));
+/// assert_eq!(holidays, vec![((1404, 1, 12), "روز جمهوری اسلامی"), ((1404, 1, 13), "روز طبیعت")]);
+/// ```
+pub fn jalali_holidays_in_range(
+    from: (i32, u32, u32),
+    to: (i32, u32, u32),
+) -> Vec<((i32, u32, u32), &'static str)> {
+    let start = jalali_to_jdn(from.0, from.1, from.2);
+    let end = jalali_to_jdn(to.0, to.1, to.2);
+    (start..=end)
+        .filter_map(|jdn| {
+            let date = jdn_to_jalali(jdn);
+            jalali_fixed_holiday_name(date.1, date.2).map(|name| (date, name))
+        })
+        .collect()
+}
+
+/// Checks whether a Jalali year is a leap year, i.e. has 366 days with a 30-day Esfand.
+///
+/// Uses the same arithmetic 33-year cycle as the conversions, in which 8 years per cycle are leap
+/// (1399, 1403 and 1408 among them), so it always agrees with `jalali_to_gregorian`. Negative years
+/// follow the same cycle.
+///
+/// # Arguments
+///
+/// * `jalali_year` - The Jalali year.
+///
+/// # Returns
+///
+/// `true` if the year has 366 days.
+///
+/// # Examples
+///
+/// ```
+/// assert!(jalali_rs::is_jalali_leap_year(1403));
+/// assert!(!jalali_rs::is_jalali_leap_year(1404));
+/// ```
+pub const fn is_jalali_leap_year(jalali_year: i32) -> bool {
+    let cycle_year = (jalali_year as i64 + 1595).rem_euclid(33);
+    cycle_year % 4 == 0 && cycle_year != 32
+}
+
+/// Checks whether a year is a leap year in the proleptic Gregorian calendar.
+///
+/// A year is leap if it is divisible by 4, except century years, which must be divisible by 400.
+/// Negative years use astronomical numbering (year 0 is 1 BCE, a leap year).
+///
+/// # Arguments
+///
+/// * `year` - The Gregorian year.
+///
+/// # Returns
+///
+/// `true` if February has 29 days in that year.
+///
+/// # Examples
+///
+/// ```
+/// assert!(jalali_rs::is_gregorian_leap_year(2000));
+/// assert!(!jalali_rs::is_gregorian_leap_year(1900));
+/// ```
+pub fn is_gregorian_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
+}
+
+/// Returns the number of days in a Jalali month.
+///
+/// Farvardin to Shahrivar (1-6) have 31 days, Mehr to Bahman (7-11) have 30, and Esfand (12) has 30 in
+/// leap years (see `is_jalali_leap_year`) and 29 otherwise.
+///
+/// # Arguments
+///
+/// * `jalali_year` - The Jalali year.
+/// * `jalali_month` - The Jalali month (1-12).
+///
+/// # Returns
+///
+/// An `Option` containing the number of days, or `None` for a month outside 1-12.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_days_in_month(1403, 12), Some(30));
+/// assert_eq!(jalali_rs::jalali_days_in_month(1404, 12), Some(29));
+/// assert_eq!(jalali_rs::jalali_days_in_month(1404, 13), None);
+/// ```
+pub fn jalali_days_in_month(jalali_year: i32, jalali_month: u32) -> Option<u32> {
+    (1..=12).contains(&jalali_month).then(|| jalali_month_length(jalali_year, jalali_month))
+}
+
+/// Converts a Jalali date to the Unix timestamp of its last second (23:59:59 UTC).
+///
+/// This is the end-of-day counterpart of `jalali_to_unix`, always exactly 86,399 seconds after it, which
+/// makes inclusive "due by the end of the day" comparisons a plain `<=`.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// An `Option` containing the Unix timestamp, or `None` if it doesn't fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_to_unix_end_of_day(1348, 10, 11), Some(86_399));
+/// ```
+pub fn jalali_to_unix_end_of_day(year: i32, month: u32, day: u32) -> Option<i64> {
+    jalali_to_unix(year, month, day)?.checked_add(86_399)
+}
+
+/// Returns the number of days in a Gregorian month.
+///
+/// February has 29 days in leap years (see `is_gregorian_leap_year`) and 28 otherwise; April, June,
+/// September and November have 30 and the other months 31.
+///
+/// # Arguments
+///
+/// * `year` - The Gregorian year.
+/// * `month` - The Gregorian month (1-12).
+///
+/// # Returns
+///
+/// An `Option` containing the number of days, or `None` for a month outside 1-12.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::gregorian_days_in_month(2024, 2), Some(29));
+/// assert_eq!(jalali_rs::gregorian_days_in_month(2025, 2), Some(28));
+/// assert_eq!(jalali_rs::gregorian_days_in_month(2025, 13), None);
+/// ```
+pub fn gregorian_days_in_month(year: i32, month: u32) -> Option<u32> {
+    match month {
+        2 if is_gregorian_leap_year(year) => Some(29),
+        2 => Some(28),
+        4 | 6 | 9 | 11 => Some(30),
+        1..=12 => Some(31),
+        _ => None,
+    }
+}
+
+/// Returns the Persian and English names of the weekday of a Gregorian date.
+///
+/// Days past the end of the month roll over into the next month, as in `gregorian_to_jalali`.
+///
+/// # Arguments
+///
+/// * `year` - The Gregorian year.
+/// * `month` - The Gregorian month (1-12).
+/// * `day` - The Gregorian day (1-31).
+///
+/// # Returns
+///
+/// A tuple containing the Persian and the English weekday name.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::gregorian_weekday_names(2025, 12, 27), ("شنبه", "Saturday"));
+/// ```
+pub fn gregorian_weekday_names(year: i32, month: u32, day: u32) -> (&'static str, &'static str) {
+    // JDN 0 was a Monday, two days after Saturday
+    let weekday = (gregorian_to_jdn(year, month as i32, day as i32) + 2).rem_euclid(7) as usize;
+    (WEEKDAY_NAMES[weekday], WEEKDAY_NAMES_EN[weekday])
+}
+
+/// Returns the Unix timestamp of 00:00 Tehran time on the Jalali day containing a timestamp.
+///
+/// Use it to bucket events by local day in Iran rather than by UTC day. Tehran is taken to be at
+/// UTC+03:30 all year, as it has been since daylight saving time was abolished in 2022; timestamps
+/// from earlier summers are bucketed an hour off.
+///
+/// # Arguments
+///
+/// * `timestamp` - A Unix timestamp in seconds.
+///
+/// # Returns
+///
+/// An `Option` containing the timestamp of Tehran midnight at or before `timestamp`, or `None` if it
+/// would overflow.
+///
+/// # Examples
+///
+/// ```
+/// // 2025-12-27 03:26:54 UTC is 06:56:54 on Dey 6 in Tehran, whose midnight is 2025-12-26 20:30 UTC
+/// assert_eq!(jalali_rs::unix_to_tehran_day_start(1766806014), Some(1766781000));
+/// ```
+pub fn unix_to_tehran_day_start(timestamp: i64) -> Option<i64> {
+    let local = timestamp.checked_add(IRAN_STANDARD_OFFSET as i64)?;
+    local.checked_sub(local.rem_euclid(86_400))?.checked_sub(IRAN_STANDARD_OFFSET as i64)
+}
+
+/// Checks whether a Jalali date exists.
+///
+/// The month must be 1-12 and the day must be between 1 and `jalali_days_in_month`, so Mehr 31 and
+/// Esfand 30 of a non-leap year such as 1404 are rejected while Esfand 30, 1403 is accepted.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month.
+/// * `day` - The Jalali day.
+///
+/// # Returns
+///
+/// `true` if the date exists in the Jalali calendar.
+///
+/// # Examples
+///
+/// ```
+/// assert!(jalali_rs::is_valid_jalali_date(1403, 12, 30));
+/// assert!(!jalali_rs::is_valid_jalali_date(1404, 12, 30));
+/// assert!(!jalali_rs::is_valid_jalali_date(1404, 7, 31));
+/// ```
+pub fn is_valid_jalali_date(year: i32, month: u32, day: u32) -> bool {
+    jalali_days_in_month(year, month).is_some_and(|days| (1..=days).contains(&day))
+}
+
+/// Moves a Jalali date to the nearest one that exists.
+///
+/// The month is clamped to 1-12, then the day to the length of that month, so Esfand 30 becomes
+/// Esfand 29 outside leap years and a day of 0 becomes the 1st. Valid dates are returned unchanged.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month, clamped to 1-12.
+/// * `day` - The Jalali day, clamped to the month's length.
+///
+/// # Returns
+///
+/// The nearest valid Jalali (year, month, day).
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_coerce_valid(1404, 10, 6), (1404, 10, 6));
+/// assert_eq!(jalali_rs::jalali_coerce_valid(1404, 12, 30), (1404, 12, 29));
+/// assert_eq!(jalali_rs::jalali_coerce_valid(1404, 13, 0), (1404, 12, 1));
+/// ```
+pub fn jalali_coerce_valid(year: i32, month: u32, day: u32) -> (i32, u32, u32) {
+    let month = month.clamp(1, 12);
+    (year, month, day.clamp(1, jalali_month_length(year, month)))
+}
+
+/// Validates a Jalali date, coercing it to the nearest valid one if it doesn't exist.
+///
+/// Lenient input handling can accept the date and still warn the user that it was adjusted. The
+/// coercion is that of `jalali_coerce_valid`.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// A tuple containing the date, coerced if needed, and `true` if it had to be coerced.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::JalaliDate;
+///
+/// let (date, coerced) = jalali_rs::jalali_validate_or_coerce(1404, 12, 30);
+/// assert_eq!(date, JalaliDate::new(1404, 12, 29).unwrap());
+/// assert!(coerced);
+/// ```
+pub fn jalali_validate_or_coerce(year: i32, month: u32, day: u32) -> (JalaliDate, bool) {
+    let (coerced_year, coerced_month, coerced_day) = jalali_coerce_valid(year, month, day);
+    let date = JalaliDate { year: coerced_year, month: coerced_month, day: coerced_day };
+    (date, (coerced_month, coerced_day) != (month, day))
+}
+
+/// The calendars `convert` can translate dates between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Calendar {
+    /// The Jalali (Solar Hijri) calendar, with the arithmetic 33-year leap cycle.
+    Jalali,
+    /// The proleptic Gregorian calendar.
+    Gregorian,
+    /// The Julian calendar, as used in Europe before October 1582.
+    Julian,
+    /// The tabular (arithmetic) Islamic calendar, from 1 Muharram 1 onwards.
+    Hijri,
+}
+
+/// Converts a date between any two supported calendars, going through its Julian Day Number.
+///
+/// This is a single total entry point: the input is checked against the rules of the source calendar
+/// and every invalid date gives `None` rather than being rolled over. `None` is also returned when the
+/// result can't be represented, such as a Hijri date before 1 Muharram 1 or a year outside `i32`.
+///
+/// # Arguments
+///
+/// * `from` - The calendar of the input date.
+/// * `to` - The calendar to convert to.
+/// * `y` - The year in the `from` calendar.
+/// * `m` - The month in the `from` calendar (1-12).
+/// * `d` - The day in the `from` calendar.
+///
+/// # Returns
+///
+/// An `Option` containing the (year, month, day) in the `to` calendar, or `None`.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::Calendar;
+///
+/// assert_eq!(jalali_rs::convert(Calendar::Jalali, Calendar::Gregorian, 1404, 10, 6), Some((2025, 12, 27)));
+/// assert_eq!(jalali_rs::convert(Calendar::Jalali, Calendar::Hijri, 1404, 4, 6), Some((1447, 1, 1)));
+/// assert_eq!(jalali_rs::convert(Calendar::Gregorian, Calendar::Jalali, 2025, 2, 30), None);
+/// ```
+pub fn convert(from: Calendar, to: Calendar, y: i32, m: u32, d: u32) -> Option<(i32, u32, u32)> {
+    let jdn = calendar_date_to_jdn(from, y, m, d)?;
+    let date = jdn_to_calendar_date(to, jdn)?;
+    // a year outside i32 wraps around in the conversion, so check it lands on the same day
+    (calendar_date_to_jdn(to, date.0, date.1, date.2) == Some(jdn)).then_some(date)
+}
+
+/// Checks whether a date exists in the proleptic Gregorian calendar.
+///
+/// The month must be 1-12 and the day must be between 1 and `gregorian_days_in_month`, so 2024-02-29 is
+/// accepted and 2023-02-29 or 2025-04-31 are rejected.
+///
+/// # Arguments
+///
+/// * `year` - The Gregorian year.
+/// * `month` - The Gregorian month.
+/// * `day` - The Gregorian day.
+///
+/// # Returns
+///
+/// `true` if the date exists in the Gregorian calendar.
+///
+/// # Examples
+///
+/// ```
+/// assert!(jalali_rs::is_valid_gregorian_date(2024, 2, 29));
+/// assert!(!jalali_rs::is_valid_gregorian_date(2023, 2, 29));
+/// ```
+pub fn is_valid_gregorian_date(year: i32, month: u32, day: u32) -> bool {
+    gregorian_days_in_month(year, month).is_some_and(|days| (1..=days).contains(&day))
+}
+
+/// Returns the first day of the Jalali month after the given one.
+///
+/// Esfand rolls over to Farvardin of the next year. Months outside 1-12 carry into neighbouring years,
+/// so month 0 is Esfand of the previous year.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+///
+/// # Returns
+///
+/// The Jalali (year, month, 1) of the next month's first day.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_start_of_next_month(1404, 10), (1404, 11, 1));
+/// assert_eq!(jalali_rs::jalali_start_of_next_month(1404, 12), (1405, 1, 1));
+/// ```
+pub fn jalali_start_of_next_month(year: i32, month: u32) -> (i32, u32, u32) {
+    jalali_add_months_clamped(year, month, 1, 1)
+}
+
+/// Returns the first day of the Jalali month before the given one.
+///
+/// Farvardin rolls back to Esfand of the previous year. Months outside 1-12 carry into neighbouring
+/// years, as in `jalali_start_of_next_month`.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+///
+/// # Returns
+///
+/// The Jalali (year, month, 1) of the previous month's first day.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_start_of_previous_month(1404, 10), (1404, 9, 1));
+/// assert_eq!(jalali_rs::jalali_start_of_previous_month(1404, 1), (1403, 12, 1));
+/// ```
+pub fn jalali_start_of_previous_month(year: i32, month: u32) -> (i32, u32, u32) {
+    jalali_add_months_clamped(year, month, 1, -1)
+}
+
+/// A valid date in the Jalali calendar.
+///
+/// Unlike a bare `(i32, u32, u32)` tuple, a `JalaliDate` can only be built through a checked constructor,
+/// so it always names a day that exists and its fields can't be swapped by accident. The free functions
+/// remain available and take and return tuples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JalaliDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl JalaliDate {
+    /// The earliest representable date, Farvardin 1 of `JALALI_MIN_YEAR`.
+    pub const MIN: JalaliDate = JalaliDate { year: JALALI_MIN_YEAR, month: 1, day: 1 };
+
+    /// The latest representable date, the last day of Esfand in `JALALI_MAX_YEAR` (the 30th if it is
+    /// a leap year, the 29th otherwise).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jalali_rs::{JalaliDate, JALALI_MAX_YEAR, JALALI_MIN_YEAR};
+    ///
+    /// assert_eq!(JalaliDate::MIN.year(), JALALI_MIN_YEAR);
+    /// assert_eq!(JalaliDate::MAX.year(), JALALI_MAX_YEAR);
+    /// assert!(JalaliDate::MIN < JalaliDate::MAX);
+    /// ```
+    pub const MAX: JalaliDate = JalaliDate {
+        year: JALALI_MAX_YEAR,
+        month: 12,
+        day: jalali_month_length(JALALI_MAX_YEAR, 12),
+    };
+
+    /// Creates a Jalali date, checking that it exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The Jalali year.
+    /// * `month` - The Jalali month (1-12).
+    /// * `day` - The Jalali day (1-31).
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the date, or `None` if `is_valid_jalali_date` rejects it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jalali_rs::JalaliDate;
+    ///
+    /// assert!(JalaliDate::new(1403, 12, 30).is_some());
+    /// assert!(JalaliDate::new(1404, 12, 30).is_none());
+    /// ```
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        is_valid_jalali_date(year, month, day).then_some(JalaliDate { year, month, day })
+    }
+
+    /// Creates the Jalali date of a Gregorian date.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The Gregorian year.
+    /// * `month` - The Gregorian month (1-12).
+    /// * `day` - The Gregorian day (1-31).
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the date, or `None` if the Gregorian date doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jalali_rs::JalaliDate;
+    ///
+    /// let date = JalaliDate::from_gregorian(2025, 12, 27).unwrap();
+    /// assert_eq!((date.year(), date.month(), date.day()), (1404, 10, 6));
+    /// ```
+    pub fn from_gregorian(year: i32, month: u32, day: u32) -> Option<Self> {
+        let (year, month, day) = convert(Calendar::Gregorian, Calendar::Jalali, year, month, day)?;
+        Some(JalaliDate { year, month, day })
+    }
+
+    /// Converts the date to the Gregorian calendar, as `jalali_to_gregorian` does.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing (gregorian_year, gregorian_month, gregorian_day).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jalali_rs::JalaliDate;
+    ///
+    /// assert_eq!(JalaliDate::new(1404, 10, 6).unwrap().to_gregorian(), (2025, 12, 27));
+    /// ```
+    pub fn to_gregorian(&self) -> (i32, u32, u32) {
+        jalali_to_gregorian(self.year, self.month as usize, self.day as i32)
+    }
+
+    /// Returns the Jalali year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the Jalali month (1-12).
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month (1-31).
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns the date a number of days later (or earlier, for a negative `delta`), as
+    /// `jalali_add_days` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jalali_rs::JalaliDate;
+    ///
+    /// let date = JalaliDate::new(1403, 12, 30).unwrap().add_days(1);
+    /// assert_eq!(date, JalaliDate::new(1404, 1, 1).unwrap());
+    /// ```
+    pub fn add_days(&self, delta: i64) -> JalaliDate {
+        let (year, month, day) = jalali_add_days(self.year, self.month, self.day, delta);
+        JalaliDate { year, month, day }
+    }
+
+    /// Returns the day of the week, as `jalali_weekday` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jalali_rs::{JalaliDate, JalaliWeekday};
+    ///
+    /// let weekday = JalaliDate::new(1404, 10, 6).unwrap().weekday();
+    /// assert_eq!(weekday, JalaliWeekday::Shanbe);
+    /// assert_eq!(weekday.persian_name(), "شنبه");
+    /// ```
+    pub fn weekday(&self) -> JalaliWeekday {
+        let index = jalali_weekday(self.year, self.month, self.day);
+        JalaliWeekday::from_index(index).expect("weekday index is below 7")
+    }
+
+    /// Formats the date with a strftime-like pattern, as `format_jalali` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jalali_rs::JalaliDate;
+    ///
+    /// let date = JalaliDate::new(1404, 10, 6).unwrap();
+    /// assert_eq!(date.format("%Y/%m/%d"), "1404/10/06");
+    /// ```
+    pub fn format(&self, pattern: &str) -> String {
+        format_jalali(self.year, self.month, self.day, pattern)
+    }
+}
+
+/// Orders dates chronologically by their Julian Day Numbers, in agreement with `jalali_diff_days`.
+impl Ord for JalaliDate {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        jalali_to_jdn(self.year, self.month, self.day).cmp(&jalali_to_jdn(other.year, other.month, other.day))
+    }
+}
+
+impl PartialOrd for JalaliDate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Formats the date as `YYYY-MM-DD` (e.g., "1404-10-06"), the format that
+/// `parse_jalali_string_to_gregorian_string` reads with a '-' separator. Width, fill and alignment
+/// flags apply to the whole string, so `format!("{:>12}", date)` right-aligns it.
+impl std::fmt::Display for JalaliDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(&format!("{:04}-{:02}-{:02}", self.year, self.month, self.day))
+    }
+}
+
+/// Parses a date in the `YYYY-MM-DD` form that `Display` produces, in Latin, Persian or Arabic
+/// digits (e.g., "1404-10-06" or "۱۴۰۴-۱۰-۰۶"). Dates that don't exist are rejected with
+/// `JalaliError::InvalidMonth` or `JalaliError::InvalidDay`.
+impl std::str::FromStr for JalaliDate {
+    type Err = JalaliError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, month, day) = parse_jalali_date_detailed(s, '-').map_err(|(error, _)| error)?;
+        Ok(JalaliDate { year, month, day })
+    }
+}
+
+/// Converts a `chrono::NaiveDate` to the Jalali calendar, as `JalaliDate::from_gregorian` does.
+/// Every `NaiveDate` has a Jalali date. Requires the `chrono` feature.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::JalaliDate;
+///
+/// let naive = chrono::NaiveDate::from_ymd_opt(2025, 12, 27).unwrap();
+/// assert_eq!(JalaliDate::from(naive), JalaliDate::new(1404, 10, 6).unwrap());
+/// ```
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for JalaliDate {
+    fn from(date: chrono::NaiveDate) -> Self {
+        use chrono::Datelike;
+        JalaliDate::from_gregorian(date.year(), date.month(), date.day())
+            .expect("chrono's year range fits in an i32 Jalali year")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl JalaliDate {
+    /// Converts the date to a `chrono::NaiveDate`. Requires the `chrono` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the Gregorian date is outside `NaiveDate`'s range, roughly the Gregorian years
+    /// -262,143 to 262,142.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jalali_rs::JalaliDate;
+    ///
+    /// let naive = JalaliDate::new(1404, 10, 6).unwrap().to_naive_date();
+    /// assert_eq!(naive, chrono::NaiveDate::from_ymd_opt(2025, 12, 27).unwrap());
+    /// ```
+    pub fn to_naive_date(&self) -> chrono::NaiveDate {
+        convert(Calendar::Jalali, Calendar::Gregorian, self.year, self.month, self.day)
+            .and_then(|(year, month, day)| chrono::NaiveDate::from_ymd_opt(year, month, day))
+            .expect("date outside chrono's NaiveDate range")
+    }
+}
+
+/// Serializes the date as its `Display` string (e.g., "1404-10-06"). Requires the `serde` feature.
+#[cfg(feature = "serde")]
+impl serde::Serialize for JalaliDate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes the date from a string through `FromStr`, so Persian and Arabic digits are accepted
+/// (e.g., "۱۴۰۴-۱۰-۰۶") and dates that don't exist are rejected. Requires the `serde` feature.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for JalaliDate {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct JalaliDateVisitor;
+
+        impl serde::de::Visitor<'_> for JalaliDateVisitor {
+            type Value = JalaliDate;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a Jalali date string such as \"1404-10-06\"")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<JalaliDate, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(JalaliDateVisitor)
+    }
+}
+
+/// Serializes a `JalaliDate` as the sortable integer YYYYMMDD (e.g., 14041006) instead of a string.
+///
+/// Use it on a field with `#[serde(with = "jalali_rs::serde_int")]`. Deserializing checks that the
+/// integer names a date that exists. Years past 214748 don't fit the encoding in an `i32` and fail to
+/// serialize. Requires the `serde` feature.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::JalaliDate;
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Row {
+///     #[serde(with = "jalali_rs::serde_int")]
+///     date: JalaliDate,
+/// }
+///
+/// let row = Row { date: JalaliDate::new(1404, 10, 6).unwrap() };
+/// assert_eq!(serde_json::to_string(&row).unwrap(), r#"{"date":14041006}"#);
+/// assert!(serde_json::from_str::<Row>(r#"{"date":14041230}"#).is_err());
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde_int {
+    use crate::{JalaliDate, jalali_from_sortable_int, jalali_to_sortable_int};
+    use serde::de::{Error as _, Unexpected};
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes the date as the integer YYYYMMDD, failing if it overflows an `i32`.
+    pub fn serialize<S: Serializer>(date: &JalaliDate, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = jalali_to_sortable_int(date.year(), date.month(), date.day())
+            .ok_or_else(|| S::Error::custom("Jalali year too large for a YYYYMMDD integer"))?;
+        serializer.serialize_i32(value)
+    }
+
+    /// Deserializes the date from the integer YYYYMMDD, rejecting dates that don't exist.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<JalaliDate, D::Error> {
+        let value = i32::deserialize(deserializer)?;
+        let (year, month, day) = jalali_from_sortable_int(value);
+        JalaliDate::new(year, month, day)
+            .ok_or_else(|| D::Error::invalid_value(Unexpected::Signed(value as i64), &"a Jalali date as YYYYMMDD"))
+    }
+}
+
+/// Moves the date forward by a number of days (backwards for a negative count), as `add_days` does.
+///
+/// # Panics
+///
+/// Panics if the result's year doesn't fit in an `i32`.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::JalaliDate;
+///
+/// let date = JalaliDate::new(1403, 12, 30).unwrap();
+/// assert_eq!(date + 1, JalaliDate::new(1404, 1, 1).unwrap());
+/// ```
+impl std::ops::Add<i64> for JalaliDate {
+    type Output = JalaliDate;
+
+    fn add(self, days: i64) -> JalaliDate {
+        self.add_days(days)
+    }
+}
+
+/// Moves the date back by a number of days (forwards for a negative count).
+///
+/// # Panics
+///
+/// Panics if the result's year doesn't fit in an `i32`.
+impl std::ops::Sub<i64> for JalaliDate {
+    type Output = JalaliDate;
+
+    fn sub(self, days: i64) -> JalaliDate {
+        let (year, month, day) = jdn_to_jalali(jalali_to_jdn(self.year, self.month, self.day) - days);
+        JalaliDate { year, month, day }
+    }
+}
+
+/// Counts the days from `other` to `self`, as `jalali_diff_days` does.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::JalaliDate;
+///
+/// let nowruz = JalaliDate::new(1405, 1, 1).unwrap();
+/// assert_eq!(nowruz - JalaliDate::new(1404, 10, 6).unwrap(), 84);
+/// ```
+impl std::ops::Sub<JalaliDate> for JalaliDate {
+    type Output = i64;
+
+    fn sub(self, other: JalaliDate) -> i64 {
+        jalali_diff_days((self.year, self.month, self.day), (other.year, other.month, other.day))
+    }
+}
+
+/// A valid date in the proleptic Gregorian calendar.
+///
+/// The Gregorian counterpart of `JalaliDate`, with the same day arithmetic, so code working in
+/// Gregorian dates doesn't have to convert to Jalali and back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GregorianDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl GregorianDate {
+    /// Creates a Gregorian date, checking that it exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The Gregorian year.
+    /// * `month` - The Gregorian month (1-12).
+    /// * `day` - The Gregorian day (1-31).
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the date, or `None` if `is_valid_gregorian_date` rejects it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jalali_rs::GregorianDate;
+    ///
+    /// assert!(GregorianDate::new(2024, 2, 29).is_some());
+    /// assert!(GregorianDate::new(2025, 2, 29).is_none());
+    /// ```
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        is_valid_gregorian_date(year, month, day).then_some(GregorianDate { year, month, day })
+    }
+
+    /// Converts the date to the Jalali calendar.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the Jalali date, or `None` if its year doesn't fit in an `i32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jalali_rs::{GregorianDate, JalaliDate};
+    ///
+    /// let date = GregorianDate::new(2025, 12, 27).unwrap();
+    /// assert_eq!(date.to_jalali(), JalaliDate::new(1404, 10, 6));
+    /// ```
+    pub fn to_jalali(&self) -> Option<JalaliDate> {
+        JalaliDate::from_gregorian(self.year, self.month, self.day)
+    }
+
+    /// Returns the Gregorian year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the Gregorian month (1-12).
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month (1-31).
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+}
+
+/// Formats the date as `YYYY-MM-DD` (e.g., "2025-12-27"), with the same padding rules as
+/// `JalaliDate`'s `Display`.
+impl std::fmt::Display for GregorianDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(&format!("{:04}-{:02}-{:02}", self.year, self.month, self.day))
+    }
+}
+
+/// Moves the date forward by a number of days (backwards for a negative count), through its Julian
+/// Day Number.
+///
+/// # Panics
+///
+/// Panics if the result's year doesn't fit in an `i32`.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::GregorianDate;
+///
+/// let date = GregorianDate::new(2025, 1, 31).unwrap();
+/// assert_eq!(date + 1, GregorianDate::new(2025, 2, 1).unwrap());
+/// ```
+impl std::ops::Add<i64> for GregorianDate {
+    type Output = GregorianDate;
+
+    fn add(self, days: i64) -> GregorianDate {
+        let jdn = gregorian_to_jdn(self.year, self.month as i32, self.day as i32);
+        let (year, month, day) = jdn_to_gregorian(jdn + days);
+        GregorianDate { year, month, day }
+    }
+}
+
+/// Moves the date back by a number of days (forwards for a negative count).
+///
+/// # Panics
+///
+/// Panics if the result's year doesn't fit in an `i32`.
+impl std::ops::Sub<i64> for GregorianDate {
+    type Output = GregorianDate;
+
+    fn sub(self, days: i64) -> GregorianDate {
+        let jdn = gregorian_to_jdn(self.year, self.month as i32, self.day as i32);
+        let (year, month, day) = jdn_to_gregorian(jdn - days);
+        GregorianDate { year, month, day }
+    }
+}
+
+/// Counts the days from `other` to `self`.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::GregorianDate;
+///
+/// let new_year = GregorianDate::new(2026, 1, 1).unwrap();
+/// assert_eq!(new_year - GregorianDate::new(2025, 12, 27).unwrap(), 5);
+/// ```
+impl std::ops::Sub<GregorianDate> for GregorianDate {
+    type Output = i64;
+
+    fn sub(self, other: GregorianDate) -> i64 {
+        gregorian_to_jdn(self.year, self.month as i32, self.day as i32)
+            - gregorian_to_jdn(other.year, other.month as i32, other.day as i32)
+    }
+}
+
+/// Adds a number of days to a Jalali date and reports whether the month or year changed.
+///
+/// Calendar renderers can use the flags to insert a header whenever the month or year flips. A year
+/// change always implies a month change. Negative `delta` values move backwards.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+/// * `delta` - The number of days to add.
+///
+/// # Returns
+///
+/// A tuple containing the resulting Jalali (year, month, day), whether the month differs from the
+/// input, and whether the year differs from the input.
+///
+/// # Examples
+///
+/// ```
+/// let (date, month_changed, year_changed) = jalali_rs::jalali_add_days_with_flags(1404, 10, 29, 3);
+/// assert_eq!(date, (1404, 11, 2));
+/// assert!(month_changed && !year_changed);
+/// ```
+pub fn jalali_add_days_with_flags(year: i32, month: u32, day: u32, delta: i64) -> ((i32, u32, u32), bool, bool) {
+    let date = jalali_add_days(year, month, day, delta);
+    let year_changed = date.0 != year;
+    (date, year_changed || date.1 != month, year_changed)
+}
+
+/// Parses a Jalali date at the start of a string and returns it together with the unparsed rest.
+///
+/// The date must open the string and is recognized like in `find_jalali_dates`: a four-digit year, a
+/// one- or two-digit month and day, separated by `-`, `/` or `.`, in Latin, Persian or Arabic digits,
+/// and not followed directly by another digit. Returns `None` if the string doesn't start with an
+/// existing Jalali date.
+///
+/// # Arguments
+///
+/// * `input` - The text to parse, e.g. a line from a log.
+///
+/// # Returns
+///
+/// An `Option` containing the Jalali (year, month, day) and the text after the date, or `None`.
+///
+/// # Examples
+///
+/// ```
+/// let (date, rest) = jalali_rs::parse_jalali_prefix("1404-10-06 rest of line").unwrap();
+/// assert_eq!(date, (1404, 10, 6));
+/// assert_eq!(rest, " rest of line");
+/// ```
+pub fn parse_jalali_prefix(input: &str) -> Option<((i32, u32, u32), &str)> {
+    let chars: Vec<char> = persian_or_arabic_digits_to_latin(input).chars().collect();
+    // digit normalization maps one character to one character, so the index carries over to the input
+    let (date, end) = match_jalali_date_at(&chars, 0)?;
+    let rest_start = input.char_indices().nth(end).map_or(input.len(), |(index, _)| index);
+    Some((date, &input[rest_start..]))
+}
+
+/// Returns the day of the week of a Jalali date, counting from Saturday.
+///
+/// The Iranian week starts on Saturday (شنبه = 0) and ends on Friday (جمعه = 6), the weekend. The
+/// weekday is derived from the date's Julian Day Number. Days past the end of the month roll over
+/// into the next month, as in `jalali_to_gregorian`.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// The weekday, from 0 (Saturday) to 6 (Friday).
+///
+/// # Examples
+///
+/// ```
+/// // Nowruz 1404 (2025-03-21) was a Friday
+/// assert_eq!(jalali_rs::jalali_weekday(1404, 1, 1), 6);
+/// assert_eq!(jalali_rs::jalali_weekday(1404, 10, 6), 0);
+/// ```
+pub fn jalali_weekday(year: i32, month: u32, day: u32) -> u8 {
+    jalali_weekday_index(year, month, day) as u8
+}
+
+/// Moves a Jalali date by a number of whole weeks.
+///
+/// The result falls on the same weekday as the input. Negative `weeks` move backwards.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+/// * `weeks` - The number of weeks to add.
+///
+/// # Returns
+///
+/// The resulting Jalali (year, month, day).
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_add_weeks(1404, 10, 20, 2), (1404, 11, 4));
+/// assert_eq!(jalali_rs::jalali_add_weeks(1404, 1, 5, -1), (1403, 12, 28));
+/// ```
+pub fn jalali_add_weeks(year: i32, month: u32, day: u32, weeks: i64) -> (i32, u32, u32) {
+    jalali_add_days(year, month, day, weeks * 7)
+}
+
+/// A day of the Iranian week, which runs from Saturday (شنبه) to Friday (جمعه).
+///
+/// The variants are ordered from Saturday, so `weekday as u8` is the index `jalali_weekday` returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum JalaliWeekday {
+    /// Saturday (شنبه), the first day of the week.
+    Shanbe,
+    /// Sunday (یکشنبه).
+    Yekshanbe,
+    /// Monday (دوشنبه).
+    Doshanbe,
+    /// Tuesday (سه‌شنبه).
+    Seshanbe,
+    /// Wednesday (چهارشنبه).
+    Chaharshanbe,
+    /// Thursday (پنجشنبه).
+    Panjshanbe,
+    /// Friday (جمعه), the weekend.
+    Jomeh,
+}
+
+impl JalaliWeekday {
+    /// Returns the weekday for an index as returned by `jalali_weekday` (Saturday = 0 to Friday = 6).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The weekday index (0-6).
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the weekday, or `None` for an index above 6.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jalali_rs::JalaliWeekday;
+    ///
+    /// let weekday = JalaliWeekday::from_index(jalali_rs::jalali_weekday(1404, 1, 1));
+    /// assert_eq!(weekday, Some(JalaliWeekday::Jomeh));
+    /// ```
+    pub fn from_index(index: u8) -> Option<Self> {
+        const WEEKDAYS: [JalaliWeekday; 7] = [
+            JalaliWeekday::Shanbe,
+            JalaliWeekday::Yekshanbe,
+            JalaliWeekday::Doshanbe,
+            JalaliWeekday::Seshanbe,
+            JalaliWeekday::Chaharshanbe,
+            JalaliWeekday::Panjshanbe,
+            JalaliWeekday::Jomeh,
+        ];
+        WEEKDAYS.get(index as usize).copied()
+    }
+
+    /// Returns the Persian name of the weekday (e.g., "شنبه").
+    pub fn persian_name(self) -> &'static str {
+        WEEKDAY_NAMES[self as usize]
+    }
+
+    /// Returns the English name of the weekday (e.g., "Saturday").
+    pub fn english_name(self) -> &'static str {
+        WEEKDAY_NAMES_EN[self as usize]
+    }
+}
+
+/// Returns the number of days in each month of a Jalali year.
+///
+/// The same values as calling `jalali_days_in_month` for months 1 to 12: six 31-day months, five 30-day
+/// months and Esfand with 30 days in leap years and 29 otherwise.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+///
+/// # Returns
+///
+/// An array of the day counts, Farvardin first.
+///
+/// # Examples
+///
+/// ```
+/// let lengths = jalali_rs::jalali_year_month_lengths(1403);
+/// assert_eq!(lengths, [31, 31, 31, 31, 31, 31, 30, 30, 30, 30, 30, 30]);
+/// ```
+pub fn jalali_year_month_lengths(year: i32) -> [u32; 12] {
+    std::array::from_fn(|index| jalali_month_length(year, index as u32 + 1))
+}
+
+/// Returns the first and last Gregorian years a Jalali year overlaps.
+///
+/// A Jalali year starts in March, so it always spans two Gregorian years: 1404 runs from 2025-03-21
+/// to 2026-03-20. The years are taken from the Gregorian dates of Farvardin 1 and the last day of Esfand.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+///
+/// # Returns
+///
+/// A tuple containing the earliest and the latest Gregorian year touched by the Jalali year.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_year_gregorian_span(1404), (2025, 2026));
+/// ```
+pub fn jalali_year_gregorian_span(year: i32) -> (i32, i32) {
+    let (start, end) = jalali_fiscal_year_gregorian_bounds(year);
+    (start.0, end.0)
+}
+
+/// Returns the Persian name of a Jalali month (e.g., "دی" for month 10).
+///
+/// # Arguments
+///
+/// * `month` - The Jalali month (1-12).
+///
+/// # Returns
+///
+/// An `Option` containing the month name, or `None` for a month outside 1-12.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_month_name(10), Some("دی"));
+/// assert_eq!(jalali_rs::jalali_month_name(13), None);
+/// ```
+pub fn jalali_month_name(month: u32) -> Option<&'static str> {
+    JALALI_MONTH_NAMES.get(month.checked_sub(1)? as usize).copied()
+}
+
+/// Returns the common Latin transliteration of a Jalali month name (e.g., "Dey" for month 10).
+///
+/// # Arguments
+///
+/// * `month` - The Jalali month (1-12).
+///
+/// # Returns
+///
+/// An `Option` containing the month name, or `None` for a month outside 1-12.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_month_name_en(1), Some("Farvardin"));
+/// assert_eq!(jalali_rs::jalali_month_name_en(0), None);
+/// ```
+pub fn jalali_month_name_en(month: u32) -> Option<&'static str> {
+    JALALI_MONTH_NAMES_EN.get(month.checked_sub(1)? as usize).copied()
+}
+
+/// Converts a Jalali date to a Gregorian date, rejecting days that don't exist.
+///
+/// `jalali_to_gregorian` rolls a day past the end of the month over into the next month, so Bahman 31
+/// comes out as Esfand 1. This checks the day against `jalali_days_in_month` first: day 31 of a 30-day
+/// month and day 30 of Esfand in a non-leap year give `None`, as do month 0 and months above 12.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// An `Option` containing (gregorian_year, gregorian_month, gregorian_day) or `None`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::try_jalali_to_gregorian(1404, 10, 6), Some((2025, 12, 27)));
+/// assert_eq!(jalali_rs::try_jalali_to_gregorian(1404, 11, 31), None);
+/// ```
+pub fn try_jalali_to_gregorian(year: i32, month: u32, day: u32) -> Option<(i32, u32, u32)> {
+    let days = jalali_days_in_month(year, month)?;
+    (1..=days).contains(&day).then(|| jalali_to_gregorian(year, month as usize, day as i32))
+}
+
+/// Formats a Jalali date with a strftime-style pattern.
+///
+/// Supported tokens:
+///
+/// * `%Y` - the year, zero-padded to four digits (e.g., "1404")
+/// * `%m` - the month, zero-padded to two digits (e.g., "10")
+/// * `%d` - the day, zero-padded to two digits (e.g., "06")
+/// * `%-m` - the month without padding (e.g., "9")
+/// * `%-d` - the day without padding (e.g., "6")
+/// * `%B` - the Persian month name (e.g., "دی")
+/// * `%A` - the Persian weekday name (e.g., "شنبه")
+/// * `%j` - the day of the year, zero-padded to three digits (e.g., "282")
+/// * `%%` - a literal percent sign
+///
+/// All other characters, including unknown tokens, are copied unchanged. The digits are Latin; pass the
+/// result through `latin_digits_to_persian` for Persian digits. `%B` is empty for a month outside 1-12.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+/// * `pattern` - The format pattern.
+///
+/// # Returns
+///
+/// The formatted string.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::format_jalali(1404, 10, 6, "%Y/%m/%d"), "1404/10/06");
+/// assert_eq!(jalali_rs::format_jalali(1404, 10, 6, "%A %d %B %Y"), "شنبه 06 دی 1404");
+/// assert_eq!(jalali_rs::format_jalali(1404, 10, 6, "%-d %B %Y"), "6 دی 1404");
+/// ```
+pub fn format_jalali(year: i32, month: u32, day: u32, pattern: &str) -> String {
+    let mut output = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            output.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('Y') => output.push_str(&format!("{:04}", year)),
+            Some('m') => output.push_str(&format!("{:02}", month)),
+            Some('d') => output.push_str(&format!("{:02}", day)),
+            Some('B') => output.push_str(jalali_month_name(month).unwrap_or_default()),
+            Some('A') => output.push_str(WEEKDAY_NAMES[jalali_weekday_index(year, month, day) as usize]),
+            Some('j') => {
+                let day_of_year = jalali_to_jdn(year, month, day) - jalali_to_jdn(year, 1, 1) + 1;
+                output.push_str(&format!("{:03}", day_of_year));
+            }
+            Some('-') => match chars.next() {
+                Some('m') => output.push_str(&month.to_string()),
+                Some('d') => output.push_str(&day.to_string()),
+                other => {
+                    output.push_str("%-");
+                    output.extend(other);
+                }
+            },
+            Some('%') => output.push('%'),
+            Some(other) => {
+                output.push('%');
+                output.push(other);
+            }
+            None => output.push('%'),
+        }
+    }
+    output
+}
+
+/// Adds a number of days to a Jalali date.
+///
+/// The date is converted to its Julian Day Number, shifted and converted back, so month lengths, leap
+/// years and Nowruz are handled exactly. Negative `delta` values move backwards.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+/// * `delta` - The number of days to add.
+///
+/// # Returns
+///
+/// The resulting Jalali (year, month, day).
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_add_days(1403, 12, 30, 1), (1404, 1, 1));
+/// assert_eq!(jalali_rs::jalali_add_days(1404, 1, 1, -1), (1403, 12, 30));
+/// ```
+pub fn jalali_add_days(year: i32, month: u32, day: u32, delta: i64) -> (i32, u32, u32) {
+    jdn_to_jalali(jalali_to_jdn(year, month, day) + delta)
+}
+
+/// Checks whether a Jalali date is the last day of its month.
+///
+/// Esfand ends on the 30th in leap years and on the 29th otherwise. Returns `false` for a month
+/// outside 1-12.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// `true` if `day` is the month's last day.
+///
+/// # Examples
+///
+/// ```
+/// assert!(jalali_rs::jalali_is_last_day_of_month(1404, 12, 29));
+/// assert!(!jalali_rs::jalali_is_last_day_of_month(1403, 12, 29));
+/// ```
+pub fn jalali_is_last_day_of_month(year: i32, month: u32, day: u32) -> bool {
+    jalali_days_in_month(year, month) == Some(day)
+}
+
+/// Returns the signed number of days from one Jalali date to another, `a - b`.
+///
+/// Computed from the dates' Julian Day Numbers, so it takes constant time and is exact across leap
+/// years. The result is positive if `a` is later than `b`, and `jalali_add_days(b, result)` gives `a`.
+///
+/// # Arguments
+///
+/// * `a` - The Jalali date to measure to, as (year, month, day).
+/// * `b` - The Jalali date to measure from, as (year, month, day).
+///
+/// # Returns
+///
+/// The number of days from `b` to `a`.
+///
+/// # Examples
+///
+/// ```
+/// // 1403 is a leap year
+/// assert_eq!(jalali_rs::jalali_diff_days((1404, 1, 1), (1403, 1, 1)), 366);
+/// assert_eq!(jalali_rs::jalali_diff_days((1404, 10, 1), (1404, 10, 6)), -5);
+/// ```
+pub fn jalali_diff_days(a: (i32, u32, u32), b: (i32, u32, u32)) -> i64 {
+    jalali_to_jdn(a.0, a.1, a.2) - jalali_to_jdn(b.0, b.1, b.2)
+}
+
+/// Parses a column of Jalali date strings into sortable `YYYYMMDD` integers.
+///
+/// Each row is parsed like in `parse_jalali_string_to_gregorian_string`, with digit normalization and
+/// validation, and encoded as `year * 10000 + month * 100 + day` (e.g., 14041006). The integers sort in
+/// calendar order, which makes them a convenient index key. Rows that aren't existing dates, or whose
+/// year is too large for the encoding, give `None`.
+///
+/// # Arguments
+///
+/// * `rows` - The date strings.
+/// * `separator` - The separator character used in the strings (e.g., '/').
+///
+/// # Returns
+///
+/// A vector with one entry per row, in the same order.
+///
+/// # Examples
+///
+/// ```
+/// let keys = jalali_rs::jalali_strings_to_sortable_ints(&["1404/10/06", "۱۴۰۳/۱۲/۳۰", "1404/12/30"], '/');
+/// assert_eq!(keys, vec![Some(14041006), Some(14031230), None]);
+/// ```
+pub fn jalali_strings_to_sortable_ints(rows: &[&str], separator: char) -> Vec<Option<i32>> {
+    rows.iter()
+        .map(|row| {
+            let (year, month, day) = parse_jalali_date(row, separator)?;
+            jalali_to_sortable_int(year, month, day)
+        })
+        .collect()
+}
+
+/// Returns the Jalali month that best labels a Gregorian month, anchored on its 15th day.
+///
+/// Jalali months start between the 19th and the 23rd of a Gregorian month, so every Gregorian month
+/// overlaps two Jalali months. The 15th is used as a stable mid-month anchor: it always falls in the
+/// Jalali month covering most of the Gregorian month (e.g., December is mostly Azar). Months outside
+/// 1-12 carry into neighbouring years, as in `gregorian_to_jalali`.
+///
+/// # Arguments
+///
+/// * `year` - The Gregorian year.
+/// * `month` - The Gregorian month (1-12).
+///
+/// # Returns
+///
+/// A tuple containing the Jalali (year, month) of the 15th of the Gregorian month.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::gregorian_month_to_jalali(2025, 12), (1404, 9));
+/// assert_eq!(jalali_rs::gregorian_month_to_jalali(2025, 3), (1403, 12));
+/// ```
+pub fn gregorian_month_to_jalali(year: i32, month: u32) -> (i32, u32) {
+    let (jalali_year, jalali_month, _) = gregorian_to_jalali(year, month as usize, 15);
+    (jalali_year, jalali_month)
+}
+
+/// Returns today's Jalali date from the system clock, in UTC.
+///
+/// The same date as `now_jalali`, without the `Option`: every timestamp the system clock can hold,
+/// including ones before 1970, maps to a date. The day changes at midnight UTC, which is 03:30 in Tehran, so between 00:00
+/// and 03:30 Tehran time this still returns the previous day.
+///
+/// # Returns
+///
+/// A tuple containing (jalali_year, jalali_month, jalali_day).
+///
+/// # Examples
+///
+/// ```
+/// let (jy, jm, jd) = jalali_rs::today();
+/// assert!(jalali_rs::is_valid_jalali_date(jy, jm, jd));
+/// ```
+pub fn today() -> (i32, u32, u32) {
+    now_jalali_from(current_unix_timestamp()).expect("the system clock is within the supported range")
+}
+
+/// Returns the half-open range of Unix timestamps covering a Jalali day in UTC.
+///
+/// The range starts at midnight UTC and ends at the next midnight, exclusive, so it suits queries
+/// like `ts >= start AND ts < end`. The end is always 86,400 seconds after the start.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// An `Option` containing (start, end), or `None` if the date doesn't exist.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_day_unix_range(1348, 10, 11), Some((0, 86_400)));
+/// ```
+pub fn jalali_day_unix_range(year: i32, month: u32, day: u32) -> Option<(i64, i64)> {
+    if !is_valid_jalali_date(year, month, day) {
+        return None;
+    }
+    let start = jalali_to_unix(year, month, day)?;
+    Some((start, start.checked_add(86_400)?))
+}
+
+/// Returns the half-open range of Unix timestamps covering a Jalali day in Tehran.
+///
+/// Like `jalali_day_unix_range`, but the day runs from midnight to midnight Tehran time (UTC+03:30,
+/// with no daylight saving time since 2022), i.e. from 20:30 UTC the evening before.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// An `Option` containing (start, end), or `None` if the date doesn't exist.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_day_unix_range_tehran(1348, 10, 11), Some((-12_600, 73_800)));
+/// ```
+pub fn jalali_day_unix_range_tehran(year: i32, month: u32, day: u32) -> Option<(i64, i64)> {
+    let (start, end) = jalali_day_unix_range(year, month, day)?;
+    Some((start - IRAN_STANDARD_OFFSET as i64, end - IRAN_STANDARD_OFFSET as i64))
+}
+
+/// Returns the ISO 8601 weekday number of a Jalali date, from Monday = 1 to Sunday = 7.
+///
+/// This is the numbering used by ISO weeks and by many databases; `jalali_weekday` counts from
+/// Saturday = 0 instead, following the Iranian week.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// The weekday, from 1 (Monday) to 7 (Sunday).
+///
+/// # Examples
+///
+/// ```
+/// // 1404-10-06 is a Saturday
+/// assert_eq!(jalali_rs::jalali_weekday_iso(1404, 10, 6), 6);
+/// ```
+pub fn jalali_weekday_iso(year: i32, month: u32, day: u32) -> u32 {
+    (jalali_weekday_index(year, month, day) + 5) % 7 + 1
+}
+
+/// Returns the weekday number of a Jalali date counting from Sunday = 0 to Saturday = 6.
+///
+/// This is the numbering used by JavaScript's `Date.getDay()` and C's `tm_wday`; `jalali_weekday`
+/// counts from Saturday = 0 instead, following the Iranian week.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// The weekday, from 0 (Sunday) to 6 (Saturday).
+///
+/// # Examples
+///
+/// ```
+/// // 1404-10-06 is a Saturday
+/// assert_eq!(jalali_rs::jalali_weekday_sunday_zero(1404, 10, 6), 6);
+/// ```
+pub fn jalali_weekday_sunday_zero(year: i32, month: u32, day: u32) -> u32 {
+    (jalali_weekday_index(year, month, day) + 6) % 7
+}
+
+/// A Jalali date with a time of day, to the second, in UTC.
+///
+/// Built through checked constructors like `JalaliDate`, so the time is always between 00:00:00 and
+/// 23:59:59. Date-times order chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JalaliDateTime {
+    date: JalaliDate,
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl JalaliDateTime {
+    /// Creates a date-time, checking that the time of day exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The Jalali date.
+    /// * `hour` - The hour (0-23).
+    /// * `minute` - The minute (0-59).
+    /// * `second` - The second (0-59).
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the date-time, or `None` if a time component is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jalali_rs::{JalaliDate, JalaliDateTime};
+    ///
+    /// let date = JalaliDate::new(1404, 10, 6).unwrap();
+    /// assert!(JalaliDateTime::new(date, 14, 30, 0).is_some());
+    /// assert!(JalaliDateTime::new(date, 24, 0, 0).is_none());
+    /// ```
+    pub fn new(date: JalaliDate, hour: u8, minute: u8, second: u8) -> Option<Self> {
+        (hour < 24 && minute < 60 && second < 60).then_some(JalaliDateTime { date, hour, minute, second })
+    }
+
+    /// Creates the date-time of a Unix timestamp (seconds since 1970-01-01 UTC).
+    ///
+    /// The timestamp is split into whole days, which give the date, and the remaining seconds, which
+    /// give the time of day. Timestamps before 1970 are supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - The Unix timestamp in seconds.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the date-time, or `None` if the year doesn't fit in an `i32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jalali_rs::JalaliDateTime;
+    ///
+    /// let date_time = JalaliDateTime::from_unix(1766806014).unwrap();
+    /// assert_eq!(date_time.date().to_string(), "1404-10-06");
+    /// assert_eq!((date_time.hour(), date_time.minute(), date_time.second()), (3, 26, 54));
+    /// ```
+    pub fn from_unix(timestamp: i64) -> Option<Self> {
+        let days = timestamp.div_euclid(86_400);
+        let seconds = timestamp.rem_euclid(86_400);
+        let (year, month, day) = checked_jdn_to_jalali(epoch::UNIX_EPOCH_JDN + days)?;
+        Some(JalaliDateTime {
+            date: JalaliDate { year, month, day },
+            hour: (seconds / 3_600) as u8,
+            minute: (seconds % 3_600 / 60) as u8,
+            second: (seconds % 60) as u8,
+        })
+    }
+
+    /// Converts the date-time to a Unix timestamp, including the time of day.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the Unix timestamp, or `None` if it doesn't fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jalali_rs::JalaliDateTime;
+    ///
+    /// assert_eq!(JalaliDateTime::from_unix(1766806014).unwrap().to_unix(), Some(1766806014));
+    /// ```
+    pub fn to_unix(&self) -> Option<i64> {
+        let midnight = jalali_to_unix(self.date.year, self.date.month, self.date.day)?;
+        midnight.checked_add(self.hour as i64 * 3_600 + self.minute as i64 * 60 + self.second as i64)
+    }
+
+    /// Returns the Jalali date.
+    pub fn date(&self) -> JalaliDate {
+        self.date
+    }
+
+    /// Returns the hour (0-23).
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute (0-59).
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second (0-59).
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+}
+
+/// Returns the number of complete weeks from one Jalali date to another.
+///
+/// The day difference `to - from` divided by 7, truncated toward zero, so 13 days is one complete
+/// week and -13 days is minus one. The result is negative if `to` is earlier than `from`.
+///
+/// # Arguments
+///
+/// * `from` - The start date as (year, month, day).
+/// * `to` - The end date as (year, month, day).
+///
+/// # Returns
+///
+/// The number of complete 7-day weeks between the dates.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_complete_weeks_between((1404, 10, 1), (1404, 10, 14)), 1);
+/// assert_eq!(jalali_rs::jalali_complete_weeks_between((1404, 10, 14), (1404, 10, 1)), -1);
+/// ```
+pub fn jalali_complete_weeks_between(from: (i32, u32, u32), to: (i32, u32, u32)) -> i64 {
+    jalali_diff_days(to, from) / 7
+}
+
+/// Converts a Gregorian ISO 8601 week date to a Jalali date.
+///
+/// ISO week 1 is the week, Monday to Sunday, that contains January 4th, so an ISO year has 52 or 53
+/// weeks and its first days may fall in the previous Gregorian year.
+///
+/// # Arguments
+///
+/// * `iso_year` - The ISO week-numbering year.
+/// * `iso_week` - The ISO week (1-52, or 1-53 in years with 53 weeks).
+/// * `iso_weekday` - The ISO weekday (Monday=1 to Sunday=7).
+///
+/// # Returns
+///
+/// An `Option` containing the Jalali date as (year, month, day), or `None` if the week or weekday
+/// doesn't exist.
+///
+/// # Examples
+///
+/// ```
+/// // Saturday of ISO week 52 of 2025 is 2025-12-27
+/// assert_eq!(jalali_rs::iso_week_to_jalali(2025, 52, 6), Some((1404, 10, 6)));
+/// // 2025 has only 52 ISO weeks
+/// assert_eq!(jalali_rs::iso_week_to_jalali(2025, 53, 1), None);
+/// ```
+pub fn iso_week_to_jalali(iso_year: i32, iso_week: u32, iso_weekday: u32) -> Option<(i32, u32, u32)> {
+    if iso_week == 0 || !(1..=7).contains(&iso_weekday) {
+        return None;
+    }
+    let week_one = iso_week_one_monday(iso_year);
+    let weeks_in_year = (iso_week_one_monday(iso_year.checked_add(1)?) - week_one) / 7;
+    if iso_week as i64 > weeks_in_year {
+        return None;
+    }
+    let jdn = week_one + 7 * (iso_week as i64 - 1) + (iso_weekday as i64 - 1);
+    checked_jdn_to_jalali(jdn)
+}
+
+/// Converts a Unix timestamp to the Jalali date on the civil clock of a given UTC offset.
+///
+/// `unix_to_jalali` gives the date at UTC, which is a day behind Tehran between 20:30 and midnight
+/// UTC. This shifts the timestamp by the offset first, so `IRAN_STANDARD_OFFSET` gives the date in
+/// Iran. Timestamps before 1970 are supported.
+///
+/// # Arguments
+///
+/// * `timestamp` - Unix timestamp in seconds.
+/// * `offset_seconds` - The offset from UTC in seconds, positive east of Greenwich.
+///
+/// # Returns
+///
+/// An `Option` containing (jalali_year, jalali_month, jalali_day), or `None` if the result is out of
+/// range.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::{unix_to_jalali_with_offset, IRAN_STANDARD_OFFSET};
+///
+/// // 2025-12-27 21:00 UTC is already 00:30 on the next day in Tehran
+/// assert_eq!(unix_to_jalali_with_offset(1766869200, 0), Some((1404, 10, 6)));
+/// assert_eq!(unix_to_jalali_with_offset(1766869200, IRAN_STANDARD_OFFSET), Some((1404, 10, 7)));
+/// ```
+pub fn unix_to_jalali_with_offset(timestamp: i64, offset_seconds: i32) -> Option<(i32, u32, u32)> {
+    let local = timestamp.checked_add(offset_seconds as i64)?;
+    JalaliDateTime::from_unix(local).map(|date_time| {
+        let date = date_time.date();
+        (date.year(), date.month(), date.day())
+    })
+}
+
+/// Converts a Jalali date to the Unix timestamp of its midnight at a given UTC offset.
+///
+/// The inverse of `unix_to_jalali_with_offset`: with `IRAN_STANDARD_OFFSET`, the result is the moment
+/// the day starts in Tehran, 3.5 hours before UTC midnight.
+///
+/// # Arguments
+///
+/// * `jalali_year` - The Jalali year.
+/// * `jalali_month` - The Jalali month (1-12).
+/// * `jalali_day` - The Jalali day (1-31).
+/// * `offset_seconds` - The offset from UTC in seconds, positive east of Greenwich.
+///
+/// # Returns
+///
+/// An `Option` containing the Unix timestamp, or `None` if it doesn't fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::{jalali_to_unix_with_offset, IRAN_STANDARD_OFFSET};
+///
+/// assert_eq!(jalali_to_unix_with_offset(1404, 10, 6, 0), Some(1766793600));
+/// assert_eq!(jalali_to_unix_with_offset(1404, 10, 6, IRAN_STANDARD_OFFSET), Some(1766781000));
+/// ```
+pub fn jalali_to_unix_with_offset(
+    jalali_year: i32,
+    jalali_month: u32,
+    jalali_day: u32,
+    offset_seconds: i32,
+) -> Option<i64> {
+    jalali_to_unix(jalali_year, jalali_month, jalali_day)?.checked_sub(offset_seconds as i64)
+}
+
+/// Returns the 1-based ordinal day of a Jalali date within its year.
+///
+/// Months 1-6 have 31 days and months 7-11 have 30, so Mehr 1 (1404-07-01) is day 187 and the last
+/// day of the year is day 365, or 366 in leap years.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// An `Option` containing the day of the year, or `None` if the date is invalid.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_day_of_year(1404, 1, 1), Some(1));
+/// assert_eq!(jalali_rs::jalali_day_of_year(1404, 7, 1), Some(187));
+/// assert_eq!(jalali_rs::jalali_day_of_year(1404, 12, 30), None);
+/// ```
+pub fn jalali_day_of_year(year: i32, month: u32, day: u32) -> Option<u32> {
+    if !is_valid_jalali_date(year, month, day) {
+        return None;
+    }
+    Some(if month <= 6 { (month - 1) * 31 + day } else { 186 + (month - 7) * 30 + day })
+}
+
+/// Returns the Jalali month and day of a 1-based ordinal day within a year.
+///
+/// The inverse of `jalali_day_of_year`.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `ordinal` - The day of the year (1-365, or 1-366 in leap years).
+///
+/// # Returns
+///
+/// An `Option` containing (month, day), or `None` if the year has no such day.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_from_day_of_year(1404, 187), Some((7, 1)));
+/// // 1403 is a leap year
+/// assert_eq!(jalali_rs::jalali_from_day_of_year(1403, 366), Some((12, 30)));
+/// assert_eq!(jalali_rs::jalali_from_day_of_year(1404, 366), None);
+/// ```
+pub fn jalali_from_day_of_year(year: i32, ordinal: u32) -> Option<(u32, u32)> {
+    let days_in_year = if is_jalali_leap_year(year) { 366 } else { 365 };
+    if ordinal == 0 || ordinal > days_in_year {
+        return None;
+    }
+    let index = ordinal - 1;
+    Some(if index < 186 {
+        (index / 31 + 1, index % 31 + 1)
+    } else {
+        ((index - 186) / 30 + 7, (index - 186) % 30 + 1)
+    })
+}
+
+/// Collects the fields of a Jalali date one at a time, then checks them together.
+///
+/// Useful when the fields arrive separately, e.g. from key-value tokens. Setting a field again replaces
+/// its value.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::{JalaliDateBuilder, JalaliError};
+///
+/// let mut builder = JalaliDateBuilder::new();
+/// builder.day(6).year(1404);
+/// assert_eq!(builder.build(), Err(JalaliError::MissingMonth));
+///
+/// builder.month(10);
+/// assert_eq!(builder.build().unwrap().to_string(), "1404-10-06");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JalaliDateBuilder {
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+}
+
+impl JalaliDateBuilder {
+    /// Creates a builder with no fields set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the Jalali year.
+    pub fn year(&mut self, year: i32) -> &mut Self {
+        self.year = Some(year);
+        self
+    }
+
+    /// Sets the Jalali month (1-12).
+    pub fn month(&mut self, month: u32) -> &mut Self {
+        self.month = Some(month);
+        self
+    }
+
+    /// Sets the Jalali day (1-31).
+    pub fn day(&mut self, day: u32) -> &mut Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// Builds the date from the fields set so far.
+    ///
+    /// # Returns
+    ///
+    /// The `JalaliDate`, or `MissingYear`, `MissingMonth` or `MissingDay` for the first field that
+    /// isn't set, then `InvalidMonth` or `InvalidDay` if the fields don't form a valid date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jalali_rs::{JalaliDateBuilder, JalaliError};
+    ///
+    /// // 1404 is not a leap year
+    /// let result = JalaliDateBuilder::new().year(1404).month(12).day(30).build();
+    /// assert_eq!(result, Err(JalaliError::InvalidDay));
+    /// ```
+    pub fn build(&self) -> Result<JalaliDate, JalaliError> {
+        let year = self.year.ok_or(JalaliError::MissingYear)?;
+        let month = self.month.ok_or(JalaliError::MissingMonth)?;
+        let day = self.day.ok_or(JalaliError::MissingDay)?;
+        if !(1..=12).contains(&month) {
+            return Err(JalaliError::InvalidMonth);
+        }
+        JalaliDate::new(year, month, day).ok_or(JalaliError::InvalidDay)
+    }
+}
+
+/// Returns the next occurrence of a Jalali month and day, on or after a date.
+///
+/// An anniversary on Esfand 30, which only exists in leap years, falls on Esfand 29 in the other
+/// years. Likewise a day past the end of any month is moved to its last day, and a month outside 1-12
+/// is clamped into that range.
+///
+/// # Arguments
+///
+/// * `month` - The month of the anniversary (1-12).
+/// * `day` - The day of the anniversary (1-31).
+/// * `from` - The date to search from as (year, month, day), returned itself if it's the anniversary.
+///
+/// # Returns
+///
+/// An `Option` containing the date of the next anniversary as (year, month, day), or `None` if it
+/// would fall after the year `i32::MAX`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_next_anniversary(10, 6, (1404, 10, 6)), Some((1404, 10, 6)));
+/// assert_eq!(jalali_rs::jalali_next_anniversary(10, 6, (1404, 10, 7)), Some((1405, 10, 6)));
+/// // 1404 is not a leap year, 1403 is
+/// assert_eq!(jalali_rs::jalali_next_anniversary(12, 30, (1404, 1, 1)), Some((1404, 12, 29)));
+/// assert_eq!(jalali_rs::jalali_next_anniversary(12, 30, (1403, 1, 1)), Some((1403, 12, 30)));
+/// ```
+pub fn jalali_next_anniversary(month: u32, day: u32, from: (i32, u32, u32)) -> Option<(i32, u32, u32)> {
+    let occurrence = |year: i32| jalali_coerce_valid(year, month, day);
+    let this_year = occurrence(from.0);
+    if jalali_diff_days(this_year, from) >= 0 {
+        Some(this_year)
+    } else {
+        from.0.checked_add(1).map(occurrence)
+    }
+}
+
+/// Returns the Persian week number of a Jalali date within its year.
+///
+/// Weeks start on Saturday. Week 1 is the week containing Farvardin 1 (Nowruz), even when Nowruz falls
+/// late in the week and the week is partial; each following Saturday starts the next week. The last
+/// week of the year is cut off at Esfand's last day, so a year has 53 or 54 weeks. This is the
+/// numbering of `jalali_to_week_string`.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+/// * `day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// An `Option` containing the week number, starting at 1, or `None` if the date is invalid.
+///
+/// # Examples
+///
+/// ```
+/// // 1404-01-01 is a Friday, so the next day starts week 2
+/// assert_eq!(jalali_rs::jalali_week_of_year(1404, 1, 1), Some(1));
+/// assert_eq!(jalali_rs::jalali_week_of_year(1404, 1, 2), Some(2));
+/// assert_eq!(jalali_rs::jalali_week_of_year(1404, 10, 6), Some(42));
+/// ```
+pub fn jalali_week_of_year(year: i32, month: u32, day: u32) -> Option<u32> {
+    is_valid_jalali_date(year, month, day).then(|| jalali_week_number(year, month, day))
+}
+
+/// Returns the quarter of the Jalali year a month falls in.
+///
+/// Quarters are three months each, matching the Iranian fiscal quarters and the seasons: Farvardin to
+/// Khordad is quarter 1 and Dey to Esfand is quarter 4.
+///
+/// # Arguments
+///
+/// * `month` - The Jalali month (1-12).
+///
+/// # Returns
+///
+/// An `Option` containing the quarter (1-4), or `None` if the month isn't 1-12.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_quarter(1), Some(1));
+/// assert_eq!(jalali_rs::jalali_quarter(10), Some(4));
+/// assert_eq!(jalali_rs::jalali_quarter(13), None);
+/// ```
+pub fn jalali_quarter(month: u32) -> Option<u8> {
+    (1..=12).contains(&month).then(|| ((month - 1) / 3 + 1) as u8)
+}
+
+/// Returns the first day of a quarter of the Jalali year.
+///
+/// # Arguments
+///
+/// * `quarter` - The quarter (1-4).
+///
+/// # Returns
+///
+/// An `Option` containing the (month, day) the quarter starts on, or `None` if the quarter isn't 1-4.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_quarter_start(3), Some((7, 1)));
+/// ```
+pub fn jalali_quarter_start(quarter: u8) -> Option<(u32, u32)> {
+    (1..=4).contains(&quarter).then(|| (quarter as u32 * 3 - 2, 1))
+}
+
+/// Returns the last day of a quarter of a Jalali year.
+///
+/// The year is needed for quarter 4, which ends on Esfand 30 in leap years and Esfand 29 otherwise.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `quarter` - The quarter (1-4).
+///
+/// # Returns
+///
+/// An `Option` containing the (month, day) the quarter ends on, or `None` if the quarter isn't 1-4.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_quarter_end(1404, 1), Some((3, 31)));
+/// // 1403 is a leap year
+/// assert_eq!(jalali_rs::jalali_quarter_end(1403, 4), Some((12, 30)));
+/// assert_eq!(jalali_rs::jalali_quarter_end(1404, 4), Some((12, 29)));
+/// ```
+pub fn jalali_quarter_end(year: i32, quarter: u8) -> Option<(u32, u32)> {
+    if !(1..=4).contains(&quarter) {
+        return None;
+    }
+    let month = quarter as u32 * 3;
+    Some((month, jalali_month_length(year, month)))
+}
+
+/// A season of the Persian year, each spanning three Jalali months.
+///
+/// The variants are ordered from spring, so `season as u32 + 1` is the season number used by
+/// `jalali_season_bounds`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Season {
+    /// Spring (بهار), Farvardin to Khordad.
+    Bahar,
+    /// Summer (تابستان), Tir to Shahrivar.
+    Tabestan,
+    /// Autumn (پاییز), Mehr to Azar.
+    Paiz,
+    /// Winter (زمستان), Dey to Esfand.
+    Zemestan,
+}
+
+impl Season {
+    /// Returns the Persian name of the season (e.g., "بهار").
+    pub fn persian_name(self) -> &'static str {
+        match self {
+            Season::Bahar => "بهار",
+            Season::Tabestan => "تابستان",
+            Season::Paiz => "پاییز",
+            Season::Zemestan => "زمستان",
+        }
+    }
+
+    /// Returns the English name of the season (e.g., "Spring").
+    pub fn english_name(self) -> &'static str {
+        match self {
+            Season::Bahar => "Spring",
+            Season::Tabestan => "Summer",
+            Season::Paiz => "Autumn",
+            Season::Zemestan => "Winter",
+        }
+    }
+}
+
+/// Returns the Persian season a Jalali month falls in.
+///
+/// Only the month is needed, since the seasons start on Farvardin 1, Tir 1, Mehr 1 and Dey 1 every year.
+///
+/// # Arguments
+///
+/// * `month` - The Jalali month (1-12).
+///
+/// # Returns
+///
+/// An `Option` containing the season, or `None` if the month isn't 1-12.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::Season;
+///
+/// assert_eq!(jalali_rs::jalali_season(1), Some(Season::Bahar));
+/// assert_eq!(jalali_rs::jalali_season(10).map(Season::persian_name), Some("زمستان"));
+/// assert_eq!(jalali_rs::jalali_season(13), None);
+/// ```
+pub fn jalali_season(month: u32) -> Option<Season> {
+    match month {
+        1..=3 => Some(Season::Bahar),
+        4..=6 => Some(Season::Tabestan),
+        7..=9 => Some(Season::Paiz),
+        10..=12 => Some(Season::Zemestan),
+        _ => None,
+    }
+}
+
+/// Returns how many days apart the same numeric date is in the Jalali and Gregorian calendars.
+///
+/// This is a diagnostic for studying how the two calendars line up, not a conversion: it reads
+/// `(jy, jm, jd)` once as a Jalali date and once as a Gregorian date and subtracts their Julian Day
+/// Numbers. The result is about 621 years of days and drifts by a few days across the year, since the
+/// months start at different points. The inputs aren't validated; out-of-range days just count on
+/// past the end of the month in each calendar.
+///
+/// # Arguments
+///
+/// * `jy` - The year, read in both calendars.
+/// * `jm` - The month (1-12), read in both calendars.
+/// * `jd` - The day, read in both calendars.
+///
+/// # Returns
+///
+/// The Jalali date's Julian Day Number minus the Gregorian date's.
+///
+/// # Examples
+///
+/// ```
+/// // 1404-10-06 (Jalali) is 2025-12-27, 226,898 days after 1404-10-06 (Gregorian)
+/// assert_eq!(jalali_rs::jalali_gregorian_jdn_delta(1404, 10, 6), 226_898);
+/// ```
+pub fn jalali_gregorian_jdn_delta(jy: i32, jm: u32, jd: u32) -> i64 {
+    jalali_to_jdn(jy, jm, jd) - gregorian_to_jdn(jy, jm as i32, jd as i32)
+}
+
+/// Parses a date string in a stated calendar and converts it to the other calendar.
+///
+/// A string like "1403-12-30" reads as a date in either calendar, so instead of guessing, the caller
+/// names the calendar it's in. A Jalali date is converted to Gregorian, and a date in any other
+/// calendar to Jalali. The fields are read like in `parse_gregorian_string_to_jalali_string`, with
+/// Persian/Arabic digits normalized, and the year is used exactly as written: "25" is year 25, not a
+/// year of the current century.
+///
+/// # Arguments
+///
+/// * `input` - The date string, as year, month and day.
+/// * `separator` - The separator character used in the input string (e.g., '-').
+/// * `source` - The calendar the input is in.
+///
+/// # Returns
+///
+/// An `Option` containing the converted date in "YYYY-MM-DD" format, or `None` if the input isn't a
+/// valid date in the source calendar.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::{convert_ambiguous_date, Calendar};
+///
+/// assert_eq!(convert_ambiguous_date("1403-12-30", '-', Calendar::Jalali), Some("2025-03-20".to_string()));
+/// assert_eq!(convert_ambiguous_date("1403-12-30", '-', Calendar::Gregorian), Some("0782-10-08".to_string()));
+/// ```
+pub fn convert_ambiguous_date(input: &str, separator: char, source: Calendar) -> Option<String> {
+    let (year, month, day) = parse_date_parts(input, separator).ok()?;
+    let target = if source == Calendar::Jalali { Calendar::Gregorian } else { Calendar::Jalali };
+    let (y, m, d) = convert(source, target, year, month, day)?;
+    Some(format!("{:04}-{:02}-{:02}", y, m, d))
+}
+
+/// Returns an iterator over every date of a Jalali year, in order.
+///
+/// Yields Farvardin 1 through the last day of Esfand: 365 dates, or 366 in leap years.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+///
+/// # Returns
+///
+/// An iterator of (year, month, day) tuples.
+///
+/// # Examples
+///
+/// ```
+/// let mut days = jalali_rs::jalali_year_days(1404);
+/// assert_eq!(days.next(), Some((1404, 1, 1)));
+/// assert_eq!(days.last(), Some((1404, 12, 29)));
+/// // 1403 is a leap year
+/// assert_eq!(jalali_rs::jalali_year_days(1403).count(), 366);
+/// ```
+pub fn jalali_year_days(year: i32) -> impl Iterator<Item = (i32, u32, u32)> {
+    (1..=12).flat_map(move |month| (1..=jalali_month_length(year, month)).map(move |day| (year, month, day)))
+}
+
+/// Converts a date in the proleptic Gregorian calendar to its Julian Day Number (JDN).
+///
+/// The JDN counts days continuously from noon of 1 January 4713 BCE (Julian), and is the common pivot
+/// every conversion in the crate goes through; astronomical libraries use it too. Dates before 4800
+/// BCE are handled with floor division. Out-of-range days count on past the end of the month.
+///
+/// # Arguments
+///
+/// * `year` - The Gregorian year, with year 0 as 1 BCE.
+/// * `month` - The Gregorian month (1-12).
+/// * `day` - The Gregorian day of the month.
+///
+/// # Returns
+///
+/// The Julian Day Number of the date.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::gregorian_to_jdn(1970, 1, 1), jalali_rs::epoch::UNIX_EPOCH_JDN);
+/// assert_eq!(jalali_rs::gregorian_to_jdn(2025, 12, 27), 2461037);
+/// ```
+pub fn gregorian_to_jdn(year: i32, month: i32, day: i32) -> i64 {
+    let a = (14 - month as i64) / 12;
+    let y = year as i64 + 4800 - a;
+    let m = month as i64 + 12 * a - 3;
+
+    // floor divisions keep the leap-year counts correct for years before 4800 BCE
+    day as i64 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400)
+        - 32045
+}
+
+/// Converts a Julian Day Number (JDN) to a date in the proleptic Gregorian calendar.
+///
+/// This is the inverse of `gregorian_to_jdn`, and is exact for every JDN whose year fits in an `i32`.
+///
+/// # Arguments
+///
+/// * `jdn` - The Julian Day Number.
+///
+/// # Returns
+///
+/// A tuple containing the Gregorian (year, month, day).
+///
+/// # Panics
+///
+/// Panics if `jdn` is beyond about ±2.3 × 10^18, a quarter of the `i64` range, where the intermediate
+/// arithmetic overflows. The year only fits in an `i32` for JDNs from -784,350,575,245 to
+/// 784,354,017,364 and wraps around outside that range. Use `checked_jdn_to_gregorian` for JDNs that
+/// may be out of range.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jdn_to_gregorian(2461037), (2025, 12, 27));
+/// ```
+pub fn jdn_to_gregorian(jdn: i64) -> (i32, u32, u32) {
+    let (year, month, day) = jdn_to_gregorian_wide(jdn).expect("Julian Day Number out of range");
+    (year as i32, month, day)
+}
+
+/// Converts a Julian Day Number (JDN) to a date in the proleptic Gregorian calendar, checking the range.
+///
+/// Like `jdn_to_gregorian`, but safe for any `i64`, such as a JDN read from untrusted input.
+///
+/// # Arguments
+///
+/// * `jdn` - The Julian Day Number.
+///
+/// # Returns
+///
+/// An `Option` containing the Gregorian (year, month, day), or `None` if the year doesn't fit in an
+/// `i32`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::checked_jdn_to_gregorian(2461037), Some((2025, 12, 27)));
+/// assert_eq!(jalali_rs::checked_jdn_to_gregorian(i64::MAX), None);
+/// ```
+pub fn checked_jdn_to_gregorian(jdn: i64) -> Option<(i32, u32, u32)> {
+    let (year, month, day) = jdn_to_gregorian_wide(jdn)?;
+    Some((i32::try_from(year).ok()?, month, day))
+}
+
+/// Converts a Jalali date to its Julian Day Number (JDN).
+///
+/// With the JDN as a shared integer pivot, date arithmetic and conversions to other calendars need no
+/// round trip through strings or tuples: adding `n` to the JDN moves the date `n` days. Out-of-range
+/// days count on past the end of the month.
+///
+/// # Arguments
+///
+/// * `jalali_year` - The Jalali year.
+/// * `jalali_month` - The Jalali month (1-12).
+/// * `jalali_day` - The Jalali day (1-31).
+///
+/// # Returns
+///
+/// The Julian Day Number of the date.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::jalali_to_jdn(1404, 10, 6), jalali_rs::gregorian_to_jdn(2025, 12, 27));
+/// assert_eq!(jalali_rs::jalali_to_jdn(1, 1, 1), jalali_rs::epoch::JALALI_EPOCH_JDN);
+/// ```
+pub fn jalali_to_jdn(jalali_year: i32, jalali_month: u32, jalali_day: u32) -> i64 {
+    jalali_to_days(jalali_year, jalali_month as i64, jalali_day as i64) + epoch::GREGORIAN_EPOCH_JDN - 366
+}
+
+/// Converts a Julian Day Number (JDN) to a Jalali date.
+///
+/// This is the inverse of `jalali_to_jdn`, and is exact for every JDN whose Jalali year fits in an
+/// `i32`.
+///
+/// # Arguments
+///
+/// * `jdn` - The Julian Day Number.
+///
+/// # Returns
+///
+/// A tuple containing the Jalali (year, month, day).
+///
+/// # Panics
+///
+/// Panics if `jdn` is within about two million days of `i64::MIN`, where the intermediate arithmetic
+/// overflows. The year only fits in an `i32` for JDNs from -784,350,185,661 to 784,354,081,571 and
+/// wraps around outside that range. Use `checked_jdn_to_jalali` for JDNs that may be out of range.
+///
+/// # Examples
+///
+/// ```
+/// let jdn = jalali_rs::jalali_to_jdn(1404, 10, 6);
+/// assert_eq!(jalali_rs::jdn_to_jalali(jdn + 1), (1404, 10, 7));
+/// ```
+pub fn jdn_to_jalali(jdn: i64) -> (i32, u32, u32) {
+    let (year, month, day) = jdn_to_jalali_wide(jdn).expect("Julian Day Number out of range");
+    (year as i32, month, day)
+}
+
+/// Converts a Julian Day Number (JDN) to a Jalali date, checking the range.
+///
+/// Like `jdn_to_jalali`, but safe for any `i64`, such as a JDN read from untrusted input.
+///
+/// # Arguments
+///
+/// * `jdn` - The Julian Day Number.
+///
+/// # Returns
+///
+/// An `Option` containing the Jalali (year, month, day), or `None` if the year doesn't fit in an `i32`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(jalali_rs::checked_jdn_to_jalali(2461037), Some((1404, 10, 6)));
+/// assert_eq!(jalali_rs::checked_jdn_to_jalali(i64::MIN), None);
+/// ```
+pub fn checked_jdn_to_jalali(jdn: i64) -> Option<(i32, u32, u32)> {
+    let (year, month, day) = jdn_to_jalali_wide(jdn)?;
+    Some((i32::try_from(year).ok()?, month, day))
+}
+
+/// Parses a Jalali date-time string like "1404-10-06 14:30:00".
+///
+/// The date and the time are separated by whitespace. The date is read like in
+/// `parse_jalali_string_to_gregorian_string`, with `-` or `/` as the separator. The time is
+/// `HH:MM:SS` or `HH:MM`, with the seconds then taken as 0. Persian/Arabic digits are normalized in
+/// both parts.
+///
+/// # Arguments
+///
+/// * `input` - The date-time string.
+///
+/// # Returns
+///
+/// An `Option` containing the `JalaliDateTime`, or `None` if the format is wrong or a component is
+/// out of range.
+///
+/// # Examples
+///
+/// ```
+/// let date_time = jalali_rs::parse_jalali_datetime("۱۴۰۴/۱۰/۰۶ ۱۴:۳۰").unwrap();
+/// assert_eq!(date_time.date().to_string(), "1404-10-06");
+/// assert_eq!((date_time.hour(), date_time.minute(), date_time.second()), (14, 30, 0));
+/// assert_eq!(jalali_rs::parse_jalali_datetime("1404-10-06 24:00:00"), None);
+/// ```
+pub fn parse_jalali_datetime(input: &str) -> Option<JalaliDateTime> {
+    let mut parts = input.split_whitespace();
+    let (date, time) = (parts.next()?, parts.next()?);
+    if parts.next().is_some() {
+        return None;
+    }
+    let (year, month, day) = parse_jalali_date(date, '-').or_else(|| parse_jalali_date(date, '/'))?;
+
+    let time = persian_or_arabic_digits_to_latin(time);
+    let fields = time.split(':').map(|field| field.parse::<u8>().ok()).collect::<Option<Vec<u8>>>()?;
+    let (hour, minute, second) = match fields[..] {
+        [hour, minute] => (hour, minute, 0),
+        [hour, minute, second] => (hour, minute, second),
+        _ => return None,
+    };
+    JalaliDateTime::new(JalaliDate { year, month, day }, hour, minute, second)
+}
+
+/// Formats a Jalali date-time as a compact, sortable timestamp like "14041006-143000".
+///
+/// The layout is `YYYYMMDD-HHMMSS`: the zero-padded year, month and day, a hyphen, then the
+/// zero-padded hour, minute and second. Strings for years 0 to 9999 sort chronologically, which suits
+/// log and backup filenames.
+///
+/// # Arguments
+///
+/// * `dt` - The Jalali date-time.
+///
+/// # Returns
+///
+/// The compact timestamp string.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::{jalali_compact_timestamp, JalaliDate, JalaliDateTime};
+///
+/// let date_time = JalaliDateTime::new(JalaliDate::new(1404, 10, 6).unwrap(), 14, 30, 0).unwrap();
+/// assert_eq!(jalali_compact_timestamp(&date_time), "14041006-143000");
+/// ```
+pub fn jalali_compact_timestamp(dt: &JalaliDateTime) -> String {
+    format!(
+        "{:04}{:02}{:02}-{:02}{:02}{:02}",
+        dt.date.year, dt.date.month, dt.date.day, dt.hour, dt.minute, dt.second
+    )
+}
+
+/// Returns an iterator over the days of a Jalali month, each with its day of the week.
+///
+/// The weekday is computed once for the first day and then advanced by one for each following day,
+/// which is the data a month grid needs. An invalid month yields nothing.
+///
+/// # Arguments
+///
+/// * `year` - The Jalali year.
+/// * `month` - The Jalali month (1-12).
+///
+/// # Returns
+///
+/// An iterator of (date, weekday) pairs, from the 1st to the last day of the month.
+///
+/// # Examples
+///
+/// ```
+/// use jalali_rs::JalaliWeekday;
+///
+/// let mut days = jalali_rs::jalali_month_days_with_weekday(1404, 10);
+/// let (first, weekday) = days.next().unwrap();
+/// assert_eq!((first.to_string(), weekday), ("1404-10-01".to_string(), JalaliWeekday::Doshanbe));
+/// assert_eq!(days.count(), 29);
+/// ```
+pub fn jalali_month_days_with_weekday(
+    year: i32,
+    month: u32,
+) -> impl Iterator<Item = (JalaliDate, JalaliWeekday)> {
+    let days = jalali_days_in_month(year, month).unwrap_or(0);
+    let first_weekday = if days > 0 { jalali_weekday_index(year, month, 1) } else { 0 };
+    (1..=days).map(move |day| {
+        let weekday = JalaliWeekday::from_index(((first_weekday + day - 1) % 7) as u8);
+        (JalaliDate { year, month, day }, weekday.expect("weekday index is below 7"))
+    })
+}
+
+// Helper function counting days from the proleptic Gregorian 0000-01-01 to a Jalali date.
+fn jalali_to_days(jalali_year: i32, jalali_month: i64, jalali_day: i64) -> i64 {
+    let jalali_year_i64: i64 = jalali_year as i64 + 1595;
+
+    // calculate total days from a fixed epoch, including Jalali leap adjustments
+    -355668
+        + (365 * jalali_year_i64)
+        + (jalali_year_i64.div_euclid(33) * 8)
+        + ((jalali_year_i64.rem_euclid(33) + 3) / 4)
+        + jalali_day
+        + if jalali_month < 7 {
+            (jalali_month - 1) * 31
+        } else {
+            ((jalali_month - 7) * 30) + 186
+        }
+}
+
+// Helper function converting a day count from the proleptic Gregorian 0000-01-01 to a Gregorian date.
+fn days_to_gregorian(mut total_days: i64) -> (i32, u32, u32) {
+    // compute Gregorian year using divisions based on Gregorian cycle lengths
+    let mut gregorian_year: i64 = 400 * total_days.div_euclid(146097);
+    total_days = total_days.rem_euclid(146097);
+    if total_days > 36524 {
+        total_days -= 1;
+        gregorian_year += 100 * (total_days / 36524);
+        total_days %= 36524;
+        if total_days >= 365 {
+            total_days += 1;
+        }
+    }
+    gregorian_year += 4 * (total_days / 1461);
+    total_days %= 1461;
+    if total_days > 365 {
+        gregorian_year += (total_days - 1) / 365;
+        total_days = (total_days - 1) % 365;
+    }
+
+    // determine Gregorian day and advance through months
+    let mut gregorian_day: i64 = total_days + 1;
+
+    let gregorian_year = gregorian_year as i32;
+    let mut gregorian_month: u32 = 1;
+    while let Some(month_length) = gregorian_days_in_month(gregorian_year, gregorian_month) {
+        if gregorian_day <= month_length as i64 {
+            break;
+        }
+        gregorian_day -= month_length as i64;
+        gregorian_month += 1;
+    }
+
+    (gregorian_year, gregorian_month, gregorian_day as u32)
+}
+
+// Helper function to convert Unix timestamp to Gregorian date.
+fn unix_to_gregorian(timestamp: i64) -> Option<(i32, u32, u32)> {
+    // floor so that times before 1970 fall on the day they belong to
+    checked_jdn_to_gregorian(epoch::UNIX_EPOCH_JDN + timestamp.div_euclid(86_400))
+}
+
+// A Jalali (year, month, day), as the free functions take and return it.
+type JalaliTuple = (i32, u32, u32);
+
+// Digit lookup tables used by the digit converters.
+const LATIN_DIGITS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+const PERSIAN_DIGITS: [char; 10] = ['۰', '۱', '۲', '۳', '۴', '۵', '۶', '۷', '۸', '۹'];
+
+// Persian names of the Jalali months, from Farvardin to Esfand.
+const JALALI_MONTH_NAMES: [&str; 12] = [
+    "فروردین", "اردیبهشت", "خرداد", "تیر", "مرداد", "شهریور",
+    "مهر", "آبان", "آذر", "دی", "بهمن", "اسفند",
+];
+
+// Latin transliterations of the Jalali month names, in the same order as `JALALI_MONTH_NAMES`.
+const JALALI_MONTH_NAMES_EN: [&str; 12] = [
+    "Farvardin", "Ordibehesht", "Khordad", "Tir", "Mordad", "Shahrivar",
+    "Mehr", "Aban", "Azar", "Dey", "Bahman", "Esfand",
+];
+
+// Helper function spelling out an integer in Persian words (e.g., 1404 -> "یک هزار و چهارصد و چهار").
+fn persian_number_words(n: i64) -> String {
+    const ONES: [&str; 20] = [
+        "", "یک", "دو", "سه", "چهار", "پنج", "شش", "هفت", "هشت", "نه",
+        "ده", "یازده", "دوازده", "سیزده", "چهارده", "پانزده", "شانزده", "هفده", "هجده", "نوزده",
+    ];
+    const TENS: [&str; 10] = ["", "", "بیست", "سی", "چهل", "پنجاه", "شصت", "هفتاد", "هشتاد", "نود"];
+    const HUNDREDS: [&str; 10] = [
+        "", "صد", "دویست", "سیصد", "چهارصد", "پانصد", "ششصد", "هفتصد", "هشتصد", "نهصد",
+    ];
+    const SCALES: [&str; 5] = ["", "هزار", "میلیون", "میلیارد", "تریلیون"];
+
+    if n == 0 {
+        return "صفر".to_string();
+    }
+    if n < 0 {
+        return format!("منفی {}", persian_number_words(n.unsigned_abs() as i64));
+    }
+
+    // split into groups of three digits, least significant first
+    let mut groups = Vec::new();
+    let mut rest = n as u64;
+    while rest > 0 {
+        groups.push((rest % 1000) as usize);
+        rest /= 1000;
+    }
+
+    let mut parts: Vec<String> = Vec::new();
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        let mut words: Vec<&str> = Vec::new();
+        if group >= 100 {
+            words.push(HUNDREDS[group / 100]);
+        }
+        let below_hundred = group % 100;
+        if below_hundred >= 20 {
+            words.push(TENS[below_hundred / 10]);
+            if below_hundred % 10 != 0 {
+                words.push(ONES[below_hundred % 10]);
+            }
+        } else if below_hundred > 0 {
+            words.push(ONES[below_hundred]);
+        }
+        let group_words = words.join(" و ");
+        parts.push(if scale == 0 {
+            group_words
+        } else {
+            format!("{} {}", group_words, SCALES[scale])
+        });
+    }
+    parts.join(" و ")
+}
+
+// Helper function spelling out a positive integer as a Persian ordinal (e.g., 6 -> "ششم").
+fn persian_ordinal_words(n: i64) -> String {
+    let words = persian_number_words(n);
+    if let Some(stem) = words.strip_suffix("سه") {
+        format!("{}سوم", stem)
+    } else if words.ends_with('ی') {
+        format!("{}\u{200c}ام", words)
+    } else {
+        format!("{}م", words)
+    }
+}
+
+// Helper function returning the number of days in a Jalali month (month must be 1-12).
+const fn jalali_month_length(jalali_year: i32, jalali_month: u32) -> u32 {
+    match jalali_month {
+        1..=6 => 31,
+        7..=11 => 30,
+        _ if is_jalali_leap_year(jalali_year) => 30,
+        _ => 29,
+    }
+}
+
+// Helper function counting days from 1970-01-01 to a Jalali date (negative before the Unix epoch).
+fn jalali_to_epoch_days(jalali_year: i32, jalali_month: u32, jalali_day: u32) -> i64 {
+    jalali_to_jdn(jalali_year, jalali_month, jalali_day) - epoch::UNIX_EPOCH_JDN
+}
+
+// Helper function reading the system clock as a Unix timestamp, rounded down to the whole second
+// (negative before the epoch).
+fn current_unix_timestamp() -> i64 {
+    match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_secs() as i64,
+        Err(error) => {
+            let before = error.duration();
+            -(before.as_secs() as i64) - i64::from(before.subsec_nanos() > 0)
+        }
+    }
+}
+
+// Helper function moving a Jalali date by whole months, clamping the day to the target month's length.
+fn jalali_add_months_clamped(jalali_year: i32, jalali_month: u32, jalali_day: u32, months: i64) -> (i32, u32, u32) {
+    let month_index = jalali_year as i64 * 12 + (jalali_month as i64 - 1) + months;
+    let year = month_index.div_euclid(12) as i32;
+    let month = month_index.rem_euclid(12) as u32 + 1;
+    (year, month, jalali_day.min(jalali_month_length(year, month)))
+}
+
+// Helper function parsing a numeric date part like `parse_date_part`, without the reason it failed.
+fn parse_number<T>(part: &str) -> Option<T>
+where
+    T: std::str::FromStr<Err = std::num::ParseIntError>,
+{
+    parse_date_part(part).ok()
+}
+
+// Arabic names of the Hijri months, from Muharram to Dhu al-Hijjah.
+const HIJRI_MONTH_NAMES: [&str; 12] = [
+    "محرم", "صفر", "ربیع‌الاول", "ربیع‌الثانی", "جمادی‌الاول", "جمادی‌الثانی",
+    "رجب", "شعبان", "رمضان", "شوال", "ذی‌القعده", "ذی‌الحجه",
+];
+
+// Helper function to convert Julian Day Number (JDN) to a tabular Hijri date.
+fn jdn_to_hijri(jdn: i64) -> Option<(i32, u32, u32)> {
+    if jdn < epoch::HIJRI_EPOCH_JDN {
+        return None;
+    }
+    // 30-year cycles of 10631 days with 11 leap years
+    let mut l = jdn - epoch::HIJRI_EPOCH_JDN + 10632;
+    let n = (l - 1) / 10631;
+    l = l - 10631 * n + 354;
+    let j = ((10985 - l) / 5316) * ((50 * l) / 17719) + (l / 5670) * ((43 * l) / 15238);
+    l = l - ((30 - j) / 15) * ((17719 * j) / 50) - (j / 16) * ((15238 * j) / 43) + 29;
+    let month = (24 * l) / 709;
+    let day = l - (709 * month) / 24;
+    let year = 30 * n + j - 30;
+    Some((year as i32, month as u32, day as u32))
+}
+
+// Persian names of the weekdays, from Saturday (the first day of the Iranian week) to Friday.
+const WEEKDAY_NAMES: [&str; 7] = ["شنبه", "یکشنبه", "دوشنبه", "سه‌شنبه", "چهارشنبه", "پنجشنبه", "جمعه"];
+
+// English names of the weekdays, in the same Saturday-first order as `WEEKDAY_NAMES`.
+const WEEKDAY_NAMES_EN: [&str; 7] = ["Saturday", "Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday"];
+
+// Helper function returning the weekday of a Jalali date, from Saturday = 0 to Friday = 6.
+fn jalali_weekday_index(jalali_year: i32, jalali_month: u32, jalali_day: u32) -> u32 {
+    // JDN 0 was a Monday, two days after Saturday
+    (jalali_to_jdn(jalali_year, jalali_month, jalali_day) + 2).rem_euclid(7) as u32
+}
+
+// Helper function naming the fixed solar-calendar public holiday on a Jalali month/day, if any.
+fn jalali_fixed_holiday_name(jalali_month: u32, jalali_day: u32) -> Option<&'static str> {
+    match (jalali_month, jalali_day) {
+        (1, 1..=4) => Some("نوروز"),
+        (1, 12) => Some("روز جمهوری اسلامی"),
+        (1, 13) => Some("روز طبیعت"),
+        (3, 14) => Some("رحلت امام خمینی"),
+        (3, 15) => Some("قیام ۱۵ خرداد"),
+        (11, 22) => Some("پیروزی انقلاب اسلامی"),
+        (12, 29) => Some("ملی شدن صنعت نفت"),
+        _ => None,
+    }
+}
+
+// Helper function matching an existing `YYYY-MM-DD` Jalali date (separated by '-', '/' or '.') at
+// `start`, returning the date and the index just past it.
+fn match_jalali_date_at(chars: &[char], start: usize) -> Option<((i32, u32, u32), usize)> {
+    let is_digit = |index: usize| chars.get(index).is_some_and(|c| c.is_ascii_digit());
+    let is_separator = |index: usize| matches!(chars.get(index), Some('-' | '/' | '.'));
+    // reads up to max_len digits from index, returning (value, end index)
+    let read_number = |index: usize, max_len: usize| {
+        let end = (index..index + max_len).take_while(|&i| is_digit(i)).last()? + 1;
+        let digits: String = chars[index..end].iter().collect();
+        Some((digits.parse::<u32>().ok()?, end))
+    };
+
+    if start > 0 && is_digit(start - 1) {
+        return None;
+    }
+    let (year, end) = read_number(start, 4).filter(|&(_, end)| end == start + 4)?;
+    let (month, end) = read_number(end + 1, 2).filter(|_| is_separator(end))?;
+    let (day, end) = read_number(end + 1, 2).filter(|_| is_separator(end))?;
+    let year = year as i32;
+    (is_valid_jalali_date(year, month, day) && !is_digit(end)).then_some(((year, month, day), end))
+}
+
+// Helper function returning the Saturday-based week number of a Jalali date, week 1 containing Farvardin 1.
+fn jalali_week_number(jalali_year: i32, jalali_month: u32, jalali_day: u32) -> u32 {
+    let day_of_year = jalali_to_jdn(jalali_year, jalali_month, jalali_day) - jalali_to_jdn(jalali_year, 1, 1);
+    (day_of_year as u32 + jalali_weekday_index(jalali_year, 1, 1)) / 7 + 1
+}
+
+// Helper function converting a valid date in any calendar to its Julian Day Number (None if invalid).
+fn calendar_date_to_jdn(calendar: Calendar, year: i32, month: u32, day: u32) -> Option<i64> {
+    match calendar {
+        Calendar::Jalali => is_valid_jalali_date(year, month, day).then(|| jalali_to_jdn(year, month, day)),
+        Calendar::Gregorian => {
+            is_valid_gregorian_date(year, month, day).then(|| gregorian_to_jdn(year, month as i32, day as i32))
+        }
+        Calendar::Julian => {
+            let jdn = julian_to_jdn(year, month, day);
+            ((1..=12).contains(&month) && jdn_to_julian(jdn) == (year, month, day)).then_some(jdn)
+        }
+        Calendar::Hijri => {
+            if year < 1 || !(1..=12).contains(&month) || !(1..=30).contains(&day) {
+                return None;
+            }
+            let (y, m) = (year as i64, month as i64);
+            // inverse of jdn_to_hijri: 354 days a year plus the 11 leap days of each 30-year cycle
+            let jdn = (11 * y + 3) / 30 + 354 * y + 30 * m - (m - 1) / 2 + day as i64
+                + epoch::HIJRI_EPOCH_JDN
+                - 385;
+            (jdn_to_hijri(jdn) == Some((year, month, day))).then_some(jdn)
+        }
+    }
+}
+
+// Helper function converting a Julian Day Number to a date in any calendar.
+fn jdn_to_calendar_date(calendar: Calendar, jdn: i64) -> Option<(i32, u32, u32)> {
+    match calendar {
+        Calendar::Jalali => Some(jdn_to_jalali(jdn)),
+        Calendar::Gregorian => Some(days_to_gregorian(jdn - epoch::GREGORIAN_EPOCH_JDN + 366)),
+        Calendar::Julian => Some(jdn_to_julian(jdn)),
+        Calendar::Hijri => jdn_to_hijri(jdn),
+    }
+}
+
+// Helper function parsing an existing Jalali date from a string with the given separator,
+// normalizing Persian/Arabic digits and ignoring thousands marks.
+fn parse_jalali_date(date_str: &str, separator: char) -> Option<(i32, u32, u32)> {
+    try_parse_jalali(date_str, separator).ok().map(|date| (date.year, date.month, date.day))
+}
+
+// Helper function splitting a date string into its numeric (year, month, day) parts, normalizing
+// Persian/Arabic digits; the parts still have to be checked against a calendar.
+fn parse_date_parts(date_str: &str, separator: char) -> Result<(i32, u32, u32), DateParseError> {
+    let normalized = persian_or_arabic_digits_to_latin(date_str);
+    let parts: Vec<&str> = normalized.split(separator).collect();
+    if parts.len() != 3 {
+        return Err(DateParseError::WrongPartCount);
+    }
+    Ok((parse_date_part(parts[0])?, parse_date_part(parts[1])?, parse_date_part(parts[2])?))
+}
+
+// Helper function parsing one numeric part of a date string, ignoring thousands marks (U+066C or ',')
+// between groups of three digits, and telling overflow apart from text that isn't a number.
+fn parse_date_part<T>(part: &str) -> Result<T, DateParseError>
+where
+    T: std::str::FromStr<Err = std::num::ParseIntError>,
+{
+    use std::num::IntErrorKind;
+    const THOUSANDS_MARKS: [char; 2] = ['\u{066C}', ','];
+    if part.contains(THOUSANDS_MARKS) {
+        // "1,404" is grouped, "1,4,0,4" and "14,04" are not
+        let unsigned = part.strip_prefix(['-', '+']).unwrap_or(part);
+        let mut groups = unsigned.split(THOUSANDS_MARKS);
+        let leading = groups.next().unwrap_or_default();
+        if !(1..=3).contains(&leading.len()) || !groups.all(|group| group.len() == 3) {
+            return Err(DateParseError::NotNumeric);
+        }
+    }
+    part.replace(THOUSANDS_MARKS, "").parse::<T>().map_err(|error| match error.kind() {
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => DateParseError::OutOfRange,
+        _ => DateParseError::NotNumeric,
+    })
+}
+
+// Helper function parsing an existing Jalali date like `parse_jalali_date`, but reporting the error
+// and the character index where it was found.
+fn parse_jalali_date_detailed(
+    date_str: &str,
+    separator: char,
+) -> Result<(i32, u32, u32), (JalaliError, usize)> {
+    let normalized: Vec<char> = persian_or_arabic_digits_to_latin(date_str).chars().collect();
+    // split into (start index, text) fields, checking each character as we go
+    let mut fields: Vec<(usize, String)> = vec![(0, String::new())];
+    for (index, &c) in normalized.iter().enumerate() {
+        if c == separator {
+            if fields.len() == 3 {
+                return Err((JalaliError::InvalidFormat, index));
+            }
+            fields.push((index + 1, String::new()));
+            continue;
+        }
+        let in_year = fields.len() == 1;
+        let (start, text) = fields.last_mut().expect("fields is never empty");
+        let sign = in_year && index == *start && (c == '-' || c == '+');
+        if !(c.is_ascii_digit() || c == ',' || c == '\u{066C}' || sign) {
+            return Err((JalaliError::InvalidDigit, index));
+        }
+        text.push(c);
+    }
+    if fields.len() != 3 {
+        return Err((JalaliError::InvalidFormat, normalized.len()));
+    }
+    let year_start = fields[0].0;
+    let jy = parse_number::<i32>(&fields[0].1).ok_or((JalaliError::InvalidFormat, year_start))?;
+    let (month_start, ref month) = fields[1];
+    let jm = parse_number::<u32>(month).ok_or((JalaliError::InvalidFormat, month_start))?;
+    let (day_start, ref day) = fields[2];
+    let jd = parse_number::<u32>(day).ok_or((JalaliError::InvalidFormat, day_start))?;
+    if !(1..=12).contains(&jm) {
+        return Err((JalaliError::InvalidMonth, month_start));
+    }
+    if !is_valid_jalali_date(jy, jm, jd) {
+        return Err((JalaliError::InvalidDay, day_start));
+    }
+    Ok((jy, jm, jd))
+}
+
+// Helper function encoding a Jalali date as the sortable integer YYYYMMDD (None if it overflows i32).
+fn jalali_to_sortable_int(jalali_year: i32, jalali_month: u32, jalali_day: u32) -> Option<i32> {
+    jalali_year.checked_mul(10_000)?.checked_add((jalali_month * 100 + jalali_day) as i32)
+}
+
+// Helper function splitting a sortable YYYYMMDD integer into (year, month, day), without validating it.
+#[cfg(feature = "serde")]
+fn jalali_from_sortable_int(value: i32) -> (i32, u32, u32) {
+    let month_day = value.rem_euclid(10_000) as u32;
+    (value.div_euclid(10_000), month_day / 100, month_day % 100)
+}
+
+// Helper function returning the JDN of the Monday that starts ISO week 1 of a year.
+fn iso_week_one_monday(iso_year: i32) -> i64 {
+    let january_4 = gregorian_to_jdn(iso_year, 1, 4);
+    // JDN 0 is a Monday
+    january_4 - january_4.rem_euclid(7)
+}
+
+// Helper function converting a Julian Day Number to a Gregorian date with an i64 year (None on overflow).
+fn jdn_to_gregorian_wide(jdn: i64) -> Option<(i64, u32, u32)> {
+    // floor divisions keep the 400-year cycle split correct before March 4801 BCE (JDN -32044),
+    // after which everything below is non-negative
+    let a = jdn.checked_add(32044)?;
+    let b = a.checked_mul(4)?.checked_add(3)?.div_euclid(146097);
+    let c = a - 146097_i64.checked_mul(b)?.div_euclid(4);
+    let d = (4 * c + 3) / 1461;
+    let e = c - (1461 * d) / 4;
+    let m = (5 * e + 2) / 153;
+    let day = (e - (153 * m + 2) / 5 + 1) as u32;
+    let month = (m + 3 - 12 * (m / 10)) as u32;
+    let year = 100 * b + d - 4800 + (m / 10);
+    Some((year, month, day))
+}
+
+// Helper function converting a Julian Day Number to a Jalali date with an i64 year (None on overflow).
+fn jdn_to_jalali_wide(jdn: i64) -> Option<(i64, u32, u32)> {
+    // days since Farvardin 1 of year -11, the start of the 33-year cycle containing the epoch
+    let days = jdn.checked_sub(epoch::JALALI_EPOCH_JDN - 4383)?;
+    let cycle = days.div_euclid(12053);
+    let mut day_in_cycle = days.rem_euclid(12053);
+
+    // a cycle is eight 4-year groups (a 366-day leap year then three 365-day years) and one
+    // extra 365-day year, which is handled as a fifth year of the last group
+    let group = (day_in_cycle / 1461).min(7);
+    day_in_cycle -= group * 1461;
+    let (year_in_group, day_of_year) = if day_in_cycle < 366 {
+        (0, day_in_cycle)
+    } else {
+        (1 + (day_in_cycle - 366) / 365, (day_in_cycle - 366) % 365)
+    };
+    let jalali_year = -11 + 33 * cycle + 4 * group + year_in_group;
+
+    let (jalali_month, jalali_day) = if day_of_year < 186 {
+        (1 + day_of_year / 31, 1 + day_of_year % 31)
+    } else {
+        (7 + (day_of_year - 186) / 30, 1 + (day_of_year - 186) % 30)
+    };
+    Some((jalali_year, jalali_month as u32, jalali_day as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gregorian_to_jalali() {
+        let (jy, jm, jd) = gregorian_to_jalali(2023, 12, 27);
+        assert_eq!(jy, 1402);
+        assert_eq!(jm, 10);
+        assert_eq!(jd, 6);
+
+        let (jy, jm, jd) = gregorian_to_jalali(2025, 12, 27);
+        assert_eq!(jy, 1404);
+        assert_eq!(jm, 10);
+        assert_eq!(jd, 6);
+    }
+
+    #[test]
+    fn test_jalali_to_gregorian() {
+        let (gy, gm, gd) = jalali_to_gregorian(1402, 10, 6);
+        assert_eq!(gy, 2023);
+        assert_eq!(gm, 12);
+        assert_eq!(gd, 27);
+
+        let (gy, gm, gd) = jalali_to_gregorian(1404, 10, 6);
+        assert_eq!(gy, 2025);
+        assert_eq!(gm, 12);
+        assert_eq!(gd, 27);
+    }
+
+    #[test]
+    fn test_jalali_to_gregorian_jdn() {
+        let (gregorian, jdn) = jalali_to_gregorian_jdn(1404, 10, 6);
+        assert_eq!(gregorian, (2025, 12, 27));
+        assert_eq!(jdn, 2461037);
+
+        for (jy, jm, jd) in [(1348, 10, 11), (1403, 12, 30), (1404, 1, 1), (1, 1, 1), (1500, 6, 31)] {
+            let ((gy, gm, gd), jdn) = jalali_to_gregorian_jdn(jy, jm, jd);
+            assert_eq!((gy, gm, gd), jalali_to_gregorian(jy, jm as usize, jd as i32));
+            assert_eq!(jdn, gregorian_to_jdn(gy, gm as i32, gd as i32));
+        }
+    }
+
+    #[test]
+    fn test_unix_to_jalali() {
+        let result = unix_to_jalali(0);
+        assert_eq!(result, Some((1348, 10, 11)));
+
+        // 1930-11-18 00:28:30 UTC
+        let result2 = unix_to_jalali(-1234567890);
+        assert_eq!(result2, Some((1309, 8, 27)));
+
+        // negative timestamps floor to the previous UTC day, as with a zero offset
+        assert_eq!(unix_to_jalali(-1), Some((1348, 10, 10)));
+        assert_eq!(unix_to_jalali(-86_400), Some((1348, 10, 10)));
+        assert_eq!(unix_to_jalali(-86_401), Some((1348, 10, 9)));
+        let far_past = (-784_350_575_245 - epoch::UNIX_EPOCH_JDN) * 86_400;
+        for timestamp in [-1, -3_599, -43_200, -86_399, -86_401, -1234567890, far_past, i64::MIN, i64::MAX] {
+            assert_eq!(unix_to_jalali(timestamp), unix_to_jalali_with_offset(timestamp, 0), "at {}", timestamp);
+        }
+    }
+
+    #[test]
+    fn test_jalali_to_unix() {
+        let result = jalali_to_unix(1348, 10, 11);
+        assert_eq!(result, Some(0));
+    }
+
+    #[test]
+    fn test_parse_gregorian_string_to_jalali_string() {
+        let result = parse_gregorian_string_to_jalali_string("2025-12-27", '-');
+        assert_eq!(result, Some("1404-10-06".to_string()));
+
+        let result_persian = parse_gregorian_string_to_jalali_string("۲۰۲۵-۱۲-۲۷", '-');
+        assert_eq!(result_persian, Some("1404-10-06".to_string()));
+
+        let invalid = parse_gregorian_string_to_jalali_string("invalid", '-');
+        assert_eq!(invalid, None);
+    }
+
+    #[test]
+    fn test_parse_jalali_string_to_gregorian_string() {
+        let result = parse_jalali_string_to_gregorian_string("1404-10-06", '-');
+        assert_eq!(result, Some("2025-12-27".to_string()));
+
+        let result_persian = parse_jalali_string_to_gregorian_string("۱۴۰۴-۱۰-۰۶", '-');
+        assert_eq!(result_persian, Some("2025-12-27".to_string()));
+
+        let invalid = parse_jalali_string_to_gregorian_string("invalid", '-');
+        assert_eq!(invalid, None);
+    }
+
+    #[test]
+    fn test_latin_digits_to_persian() {
+        let result = latin_digits_to_persian("1400-12-10");
+        assert_eq!(result, "۱۴۰۰-۱۲-۱۰");
+    }
+
+    #[test]
+    fn test_persian_or_arabic_digits_to_latin() {
+        let result_persian = persian_or_arabic_digits_to_latin("۱۴۰۰-۱۲-۱۰");
+        assert_eq!(result_persian, "1400-12-10");
+
+        let result_arabic = persian_or_arabic_digits_to_latin("١٤٠٠-١٢-١٠");
+        assert_eq!(result_arabic, "1400-12-10");
+
+        let mixed = persian_or_arabic_digits_to_latin("۴٤۵٥۶٦"); // Persian 4, Arabic 4,5,6
+        assert_eq!(mixed, "445566");
+    }
+
+    #[test]
+    fn test_jalali_date_to_persian_words() {
+        let result = jalali_date_to_persian_words(1404, 10, 6);
+        assert_eq!(result, "ششم دی ماه یک هزار و چهارصد و چهار");
+
+        let result = jalali_date_to_persian_words(1403, 12, 30);
+        assert_eq!(result, "سی\u{200c}ام اسفند ماه یک هزار و چهارصد و سه");
+
+        let result = jalali_date_to_persian_words(1399, 1, 23);
+        assert_eq!(result, "بیست و سوم فروردین ماه یک هزار و سیصد و نود و نه");
+
+        assert_eq!(jalali_date_to_persian_words(1404, 13, 1), "");
+    }
+
+    #[test]
+    fn test_parse_jalali_natural() {
+        assert_eq!(parse_jalali_natural("۱۴۰۴ دی ۶"), Some((1404, 10, 6)));
+        assert_eq!(parse_jalali_natural("۶ دی ماه ۱۴۰۴"), Some((1404, 10, 6)));
+        assert_eq!(parse_jalali_natural("دی ۶، ۱۴۰۴"), Some((1404, 10, 6)));
+        assert_eq!(parse_jalali_natural("1403 30 اسفند"), Some((1403, 12, 30)));
+        assert_eq!(parse_jalali_natural("۶ دي ۱۴۰۴"), Some((1404, 10, 6))); // Arabic yeh
+
+        assert_eq!(parse_jalali_natural("۱۴۰۴ ۱۰ ۶"), None);
+        assert_eq!(parse_jalali_natural("۶ ۷ دی"), None);
+        assert_eq!(parse_jalali_natural("۱۴۰۴ دی بهمن ۶"), None);
+        assert_eq!(parse_jalali_natural("۱۴۰۴ فلان ۶"), None);
+    }
+
+    #[test]
+    fn test_try_gregorian_to_jalali() {
+        assert_eq!(try_gregorian_to_jalali(2025, 12, 27), Some((1404, 10, 6)));
+
+        // day 0 is the last day of the previous month: 2025-11-30
+        assert_eq!(try_gregorian_to_jalali(2025, 12, 0), Some(gregorian_to_jalali(2025, 11, 30)));
+        // day -5 borrows five more days: 2025-11-25
+        assert_eq!(try_gregorian_to_jalali(2025, 12, -5), Some(gregorian_to_jalali(2025, 11, 25)));
+        // borrowing across a year boundary: 2024-12-31
+        assert_eq!(try_gregorian_to_jalali(2025, 1, 0), Some(gregorian_to_jalali(2024, 12, 31)));
+
+        assert_eq!(try_gregorian_to_jalali(2025, 0, 1), None);
+        assert_eq!(try_gregorian_to_jalali(2025, 13, 1), None);
+    }
+
+    #[test]
+    fn test_jalali_month_weekday_counts() {
+        // Dey 1404 starts on Monday 2025-12-22
+        assert_eq!(jalali_month_weekday_counts(1404, 10), [4, 4, 5, 5, 4, 4, 4]);
+
+        for (year, month, days) in [(1404, 1, 31), (1404, 7, 30), (1403, 12, 30), (1404, 12, 29)] {
+            let counts = jalali_month_weekday_counts(year, month);
+            assert_eq!(counts.iter().sum::<u32>(), days);
+        }
+
+        assert_eq!(jalali_month_weekday_counts(1404, 13), [0; 7]);
+    }
+
+    #[test]
+    fn test_epoch_constants() {
+        assert_eq!(gregorian_to_jdn(1970, 1, 1), epoch::UNIX_EPOCH_JDN);
+        assert_eq!(gregorian_to_jdn(1, 1, 1), epoch::GREGORIAN_EPOCH_JDN);
+        assert_eq!(jalali_to_gregorian_jdn(1, 1, 1).1, epoch::JALALI_EPOCH_JDN);
+        assert_eq!(jdn_to_gregorian(epoch::UNIX_EPOCH_JDN), (1970, 1, 1));
+        assert_eq!(jdn_to_jalali(epoch::JALALI_EPOCH_JDN), (1, 1, 1));
+    }
+
+    #[test]
+    fn test_jalali_fiscal_year_gregorian_bounds() {
+        // 1403 is a leap year, so it ends on Esfand 30
+        let (start, end) = jalali_fiscal_year_gregorian_bounds(1403);
+        assert_eq!(start, (2024, 3, 20));
+        assert_eq!(end, (2025, 3, 20));
+        assert_eq!(end, jalali_to_gregorian(1403, 12, 30));
+
+        let (start, end) = jalali_fiscal_year_gregorian_bounds(1404);
+        assert_eq!(start, (2025, 3, 21));
+        assert_eq!(end, jalali_to_gregorian(1404, 12, 29));
+    }
+
+    #[test]
+    fn test_parse_jalali_datetime_string_to_gregorian_string() {
+        let result = parse_jalali_datetime_string_to_gregorian_string("۱۴۰۴-۱۰-۰۶ ۱۲:۳۰", '-');
+        assert_eq!(result, Some("2025-12-27".to_string()));
+
+        let date_only = parse_jalali_datetime_string_to_gregorian_string("1404/10/06", '/');
+        assert_eq!(date_only, Some("2025-12-27".to_string()));
+
+        let invalid = parse_jalali_datetime_string_to_gregorian_string("1404-13-06 12:30", '-');
+        assert_eq!(invalid, None);
+
+        let empty = parse_jalali_datetime_string_to_gregorian_string("   ", '-');
+        assert_eq!(empty, None);
+    }
+
+    #[test]
+    fn test_jalali_cmp() {
+        use std::cmp::Ordering;
+
+        assert_eq!(jalali_cmp((1403, 12, 30), (1404, 1, 1)), Ordering::Less);
+        assert_eq!(jalali_cmp((1404, 1, 1), (1403, 12, 30)), Ordering::Greater);
+        assert_eq!(jalali_cmp((1404, 10, 6), (1404, 10, 6)), Ordering::Equal);
+
+        let mut dates = vec![(1404, 1, 2), (1403, 12, 29), (1404, 1, 1), (1403, 12, 30)];
+        dates.sort_by(|a, b| jalali_cmp(*a, *b));
+        assert_eq!(dates, vec![(1403, 12, 29), (1403, 12, 30), (1404, 1, 1), (1404, 1, 2)]);
+    }
+
+    #[test]
+    fn test_parse_persian_duration() {
+        assert_eq!(parse_persian_duration("۲ هفته"), Some(14));
+        assert_eq!(parse_persian_duration("۱۰ روز"), Some(10));
+        assert_eq!(parse_persian_duration("۳ ماه"), Some(90));
+        assert_eq!(parse_persian_duration("1 سال"), Some(365));
+        assert_eq!(parse_persian_duration("۵روز"), Some(5));
+
+        assert_eq!(parse_persian_duration("هفته"), None);
+        assert_eq!(parse_persian_duration("۲ قرن"), None);
+        assert_eq!(parse_persian_duration(""), None);
+    }
+
+    #[test]
+    fn test_write_jalali() {
+        let mut buffer = String::new();
+        write_jalali(&mut buffer, 1404, 10, 6).unwrap();
+        assert_eq!(Some(buffer.clone()), parse_gregorian_string_to_jalali_string("2025-12-27", '-'));
+
+        // the buffer is appended to, not replaced
+        buffer.push(' ');
+        write_jalali(&mut buffer, 99, 1, 1).unwrap();
+        assert_eq!(buffer, "1404-10-06 0099-01-01");
+    }
+
+    #[test]
+    fn test_early_jalali_years() {
+        // anchors in the modern era, checked against published calendars
+        assert_eq!(jalali_to_gregorian(1300, 1, 1), (1921, 3, 21));
+        assert_eq!(jalali_to_gregorian(1357, 11, 22), (1979, 2, 11));
+        assert_eq!(gregorian_to_jalali(1921, 3, 21), (1300, 1, 1));
+
+        // the arithmetic epoch: Farvardin 1, year 1
+        assert_eq!(jalali_to_gregorian(1, 1, 1), (622, 3, 21));
+        assert_eq!(gregorian_to_jalali(622, 3, 21), (1, 1, 1));
+        assert_eq!(gregorian_to_jalali(622, 3, 20), (0, 12, 29));
+
+        // every day of the early years round-trips and the day count stays contiguous
+        for year in [1, 100, 500] {
+            let mut previous_jdn = jalali_to_jdn(year - 1, 12, jalali_month_length(year - 1, 12));
+            for month in 1..=12 {
+                for day in 1..=jalali_month_length(year, month) {
+                    let ((gy, gm, gd), jdn) = jalali_to_gregorian_jdn(year, month, day);
+                    assert_eq!(jdn, previous_jdn + 1);
+                    assert_eq!(gregorian_to_jalali(gy, gm as usize, gd as i32), (year, month, day));
+                    previous_jdn = jdn;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_jalali_months_ago_gregorian() {
+        // 1766806014 is 1404-10-06 (2025-12-27)
+        let now = 1766806014;
+        assert_eq!(jalali_months_ago_gregorian_from(0, now), Some((2025, 12, 27)));
+        // 1404-07-06
+        assert_eq!(jalali_months_ago_gregorian_from(3, now), Some((2025, 9, 28)));
+        // 1403-10-06
+        assert_eq!(jalali_months_ago_gregorian_from(12, now), Some((2024, 12, 26)));
+        // a negative count goes forward, to 1405-01-06
+        assert_eq!(jalali_months_ago_gregorian_from(-3, now), Some((2026, 3, 26)));
+        // 1404-03-31 minus 3 months clamps to 1403-12-30
+        let khordad_31 = jalali_to_unix(1404, 3, 31).unwrap();
+        assert_eq!(jalali_months_ago_gregorian_from(3, khordad_31), Some((2025, 3, 20)));
+
+        assert!(jalali_months_ago_gregorian(3).is_some());
+
+        // the month stepping clamps the day to the target month
+        assert_eq!(jalali_add_months_clamped(1404, 3, 31, -3), (1403, 12, 30));
+        assert_eq!(jalali_add_months_clamped(1405, 3, 31, -3), (1404, 12, 29));
+        assert_eq!(jalali_add_months_clamped(1404, 10, 6, 3), (1405, 1, 6));
+    }
+
+    #[test]
+    fn test_now_jalali() {
+        assert_eq!(now_jalali_from(0), Some((1348, 10, 11)));
+        assert_eq!(now_jalali_from(1766806014), Some((1404, 10, 6)));
+        // the last second of the UTC day still belongs to it
+        assert_eq!(now_jalali_from(1766793600 + 86_399), Some((1404, 10, 6)));
+        // a clock before the epoch rounds down to the earlier day
+        assert_eq!(now_jalali_from(-1), Some((1348, 10, 10)));
+        assert_eq!(now_jalali_from(-86_400), Some((1348, 10, 10)));
+        for timestamp in [-86_401, -1, 0, 1766806014, i64::MAX] {
+            assert_eq!(now_jalali_from(timestamp), unix_to_jalali(timestamp), "at {}", timestamp);
+        }
+
+        let today = now_jalali().unwrap();
+        assert!(today >= (1404, 10, 6));
+    }
+
+    #[test]
+    fn test_thousands_separator_in_year() {
+        let result = parse_jalali_string_to_gregorian_string("۱٬۴۰۴-۱۰-۰۶", '-');
+        assert_eq!(result, Some("2025-12-27".to_string()));
+
+        let result = parse_gregorian_string_to_jalali_string("2,025/12/27", '/');
+        assert_eq!(result, Some("1404-10-06".to_string()));
+
+        // a comma separator still splits the date parts
+        let result = parse_jalali_string_to_gregorian_string("1404,10,06", ',');
+        assert_eq!(result, Some("2025-12-27".to_string()));
+
+        assert_eq!(parse_jalali_natural("۶ دی ۱٬۴۰۴"), Some((1404, 10, 6)));
+        assert_eq!(parse_jalali_string_to_gregorian_string("٬-۱۰-۰۶", '-'), None);
+
+        // the marks must separate groups of three digits
+        assert_eq!(parse_jalali_string_to_gregorian_string("1,4,0,4-10-06", '-'), None);
+        assert_eq!(parse_jalali_string_to_gregorian_string("14,04-10-06", '-'), None);
+        assert_eq!(parse_jalali_string_to_gregorian_string("1404-1,0-06", '-'), None);
+        assert_eq!(parse_jalali_string_to_gregorian_string("1,404,-10-06", '-'), None);
+        assert_eq!(try_parse_jalali("1,4,0,4/10/06", '/'), Err(DateParseError::NotNumeric));
+        assert_eq!(try_parse_jalali("-1,404/01/01", '/'), Ok(JalaliDate::new(-1404, 1, 1).unwrap()));
+    }
+
+    #[test]
+    fn test_digit_converters_pass_through_unusual_unicode() {
+        // emoji (including a ZWJ sequence), combining marks, RTL/bidi controls and ZWNJ
+        let unusual = "😀👨\u{200d}👩 e\u{0301} \u{064b}\u{0670} \u{200f}\u{202b}\u{2067}\u{200c}\u{feff}";
+        assert_eq!(latin_digits_to_persian(unusual), unusual);
+        assert_eq!(persian_or_arabic_digits_to_latin(unusual), unusual);
+
+        // digits next to the unusual characters are still converted
+        let mixed = format!("{}1۲٣{}", unusual, unusual);
+        assert_eq!(persian_or_arabic_digits_to_latin(&mixed), format!("{}123{}", unusual, unusual));
+        assert_eq!(latin_digits_to_persian(&mixed), format!("{}۱۲٣{}", unusual, unusual));
+
+        // the characters bordering the digit ranges are left alone
+        let borders = "/:\u{065f}\u{066a}\u{06ef}\u{06fa}";
+        assert_eq!(latin_digits_to_persian(borders), borders);
+        assert_eq!(persian_or_arabic_digits_to_latin(borders), borders);
+    }
+
+    #[test]
+    fn test_jalali_season_bounds() {
+        assert_eq!(jalali_season_bounds(1404, 1), Some(((1404, 1, 1), (1404, 3, 31))));
+        assert_eq!(jalali_season_bounds(1404, 2), Some(((1404, 4, 1), (1404, 6, 31))));
+        assert_eq!(jalali_season_bounds(1404, 3), Some(((1404, 7, 1), (1404, 9, 30))));
+
+        // Zemestan ends on Esfand 30 in a leap year and Esfand 29 otherwise
+        assert_eq!(jalali_season_bounds(1403, 4), Some(((1403, 10, 1), (1403, 12, 30))));
+        assert_eq!(jalali_season_bounds(1404, 4), Some(((1404, 10, 1), (1404, 12, 29))));
+
+        assert_eq!(jalali_season_bounds(1404, 0), None);
+        assert_eq!(jalali_season_bounds(1404, 5), None);
+    }
+
+    #[test]
+    fn test_tuple_conversions() {
+        assert_eq!(gregorian_tuple_to_jalali((2025, 12, 27)), (1404, 10, 6));
+        assert_eq!(jalali_tuple_to_gregorian((1404, 10, 6)), (2025, 12, 27));
+
+        let jalali = vec![(1403, 12, 30), (1404, 1, 1), (1348, 10, 11)];
+        let round_trip: Vec<_> = jalali
+            .iter()
+            .copied()
+            .map(jalali_tuple_to_gregorian)
+            .map(gregorian_tuple_to_jalali)
+            .collect();
+        assert_eq!(round_trip, jalali);
+    }
+
+    #[test]
+    fn test_jalali_same_month_and_year() {
+        assert!(jalali_same_month((1404, 10, 1), (1404, 10, 30)));
+        // same month, different year
+        assert!(!jalali_same_month((1403, 10, 6), (1404, 10, 6)));
+        assert!(!jalali_same_year((1403, 10, 6), (1404, 10, 6)));
+        // same year, different month
+        assert!(!jalali_same_month((1404, 9, 30), (1404, 10, 1)));
+        assert!(jalali_same_year((1404, 9, 30), (1404, 10, 1)));
+    }
+
+    #[test]
+    fn test_jalali_to_hijri() {
+        // 1 Muharram 1447 fell on 2025-06-27 (1404-04-06)
+        assert_eq!(jalali_to_hijri(1404, 4, 6), Some((1447, 1, 1)));
+        assert_eq!(jalali_to_hijri(1404, 4, 5), Some((1446, 12, 29)));
+        assert_eq!(jalali_to_hijri_string(1404, 4, 6), "1 محرم 1447");
+        assert_eq!(jalali_to_hijri_string(1404, 10, 6), "7 رجب 1447");
+
+        // 1 Muharram 1 is 622-07-19 in the proleptic Gregorian calendar
+        let (jy, jm, jd) = gregorian_to_jalali(622, 7, 19);
+        assert_eq!(jalali_to_hijri(jy, jm, jd), Some((1, 1, 1)));
+        assert_eq!(jalali_to_hijri(1, 1, 1), None);
+        assert_eq!(jalali_to_hijri_string(1, 1, 1), "");
+    }
+
+    #[test]
+    fn test_jalali_is_cycle_start() {
+        // two consecutive cycle starts, 33 years apart, with no start in between
+        assert!(jalali_is_cycle_start(1375));
+        assert!(jalali_is_cycle_start(1408));
+        assert!((1376..1408).all(|year| !jalali_is_cycle_start(year)));
+
+        // each cycle starts with a leap year and holds 8 of them
+        assert_eq!(jalali_month_length(1408, 12), 30);
+        let leap_years = (1375..1408).filter(|&year| jalali_month_length(year, 12) == 30).count();
+        assert_eq!(leap_years, 8);
+
+        assert!(jalali_is_cycle_start(-1595));
+    }
+
+    // Randomized harness for the string parsers. A small xorshift generator keeps it deterministic
+    // and dependency-free. The invariants checked for every input are:
+    // * neither parser panics, whatever the input;
+    // * a `Some` result is a zero-padded `YYYY-MM-DD` string of an existing date, i.e. feeding it to
+    //   the opposite parser gives back the original date in the same zero-padded form;
+    // * inputs naming a day past the end of its month (e.g., 1404-07-31 or 2023-02-29) return `None`.
+    #[test]
+    fn test_parsers_never_panic_on_arbitrary_input() {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = move |bound: u64| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % bound
+        };
+
+        let alphabet: Vec<char> = "0123456789۰۱۲۳۴۵۶۷۸۹٠١٢٣٤٥٦٧٨٩-/. +٬,aی😀\u{200f}".chars().collect();
+        let separators = ['-', '/', '.', ' ', ','];
+
+        type Parser = fn(&str, char) -> Option<String>;
+        let jalali_parser: Parser = parse_jalali_string_to_gregorian_string;
+        let gregorian_parser: Parser = parse_gregorian_string_to_jalali_string;
+
+        // a result must convert back to itself (outputs with negative years use '-' as a sign,
+        // so they can't be re-read with '-' as the separator)
+        let round_trips = |output: &str, forward: Parser, backward: Parser| {
+            if output.starts_with('-') {
+                return;
+            }
+            let converted = backward(output, '-').unwrap();
+            if !converted.starts_with('-') {
+                assert_eq!(forward(&converted, '-').as_deref(), Some(output));
+            }
+        };
+        let check = |input: &str, separator: char| {
+            if let Some(gregorian) = jalali_parser(input, separator) {
+                round_trips(&gregorian, jalali_parser, gregorian_parser);
+            }
+            if let Some(jalali) = gregorian_parser(input, separator) {
+                round_trips(&jalali, gregorian_parser, jalali_parser);
+            }
+        };
+
+        // arbitrary strings drawn from digits in all scripts, separators and stray characters
+        for _ in 0..20_000 {
+            let length = next(16);
+            let input: String = (0..length).map(|_| alphabet[next(alphabet.len() as u64) as usize]).collect();
+            check(&input, separators[next(separators.len() as u64) as usize]);
+        }
+
+        // date-shaped strings with out-of-range and extreme parts
+        let years = [i32::MIN, -5000, -1, 0, 1, 622, 1404, 2025, 9999, i32::MAX - 700, i32::MAX];
+        for _ in 0..20_000 {
+            let year = if next(4) == 0 {
+                years[next(years.len() as u64) as usize]
+            } else {
+                next(6000) as i32 - 3000
+            };
+            let (month, day) = (next(15), next(34));
+            let separator = separators[next(4) as usize];
+            let mut input = format!("{}{}{}{}{}", year, separator, month, separator, day);
+            if next(2) == 0 {
+                input = latin_digits_to_persian(&input);
+            }
+            check(&input, separator);
+        }
+
+        // days past the end of the month are rejected rather than rolled over
+        assert_eq!(parse_jalali_string_to_gregorian_string("1404-07-31", '-'), None);
+        assert_eq!(parse_jalali_string_to_gregorian_string("1404-12-30", '-'), None);
+        assert_eq!(parse_gregorian_string_to_jalali_string("2023-02-29", '-'), None);
+        assert_eq!(parse_gregorian_string_to_jalali_string("2024-02-29", '-'), Some("1402-12-10".to_string()));
+
+        // the conversion functions themselves tolerate out-of-range months
+        assert_eq!(gregorian_to_jalali(2025, 0, 1), gregorian_to_jalali(2024, 12, 1));
+        assert_eq!(gregorian_to_jalali(2025, 13, 27), gregorian_to_jalali(2026, 1, 27));
+    }
+
+    #[test]
+    fn test_describe_jalali() {
+        let description = describe_jalali(1404, 10, 6).unwrap();
+        assert_eq!(
+            description,
+            DateDescription {
+                jalali: (1404, 10, 6),
+                gregorian: (2025, 12, 27),
+                weekday_name: "شنبه",
+                month_name: "دی",
+                is_weekend: false,
+                is_holiday: false,
+            }
+        );
+
+        let nowruz = describe_jalali(1404, 1, 1).unwrap();
+        assert_eq!(nowruz.weekday_name, "جمعه");
+        assert!(nowruz.is_weekend && nowruz.is_holiday);
+
+        assert_eq!(describe_jalali(1404, 12, 30), None);
+        assert_eq!(describe_jalali(1404, 13, 1), None);
+    }
+
+    #[test]
+    fn test_find_jalali_dates() {
+        let text = "قرارداد در ۱۴۰۴/۰۱/۱۵ امضا شد و تا 1405.1.14 اعتبار دارد.";
+        assert_eq!(find_jalali_dates(text), vec![(1404, 1, 15), (1405, 1, 14)]);
+
+        // invalid dates and digit runs that are too long are ignored
+        let text = "1404-07-31, 1404-12-30, 12345-01-01, 1404-01-011, 1403-12-30";
+        assert_eq!(find_jalali_dates(text), vec![(1403, 12, 30)]);
+
+        assert_eq!(find_jalali_dates("بدون تاریخ"), vec![]);
+        assert_eq!(find_jalali_dates("1404-10-"), vec![]);
+    }
+
+    #[test]
+    fn test_jalali_to_gregorian_debug_cross_check() {
+        // exercises the debug round-trip assertion across every day of a wide range of years
+        for year in (-3000..3000).step_by(7).chain([i32::MIN, i32::MAX - 622, i32::MAX]) {
+            for month in 1..=12 {
+                for day in 1..=jalali_month_length(year, month) {
+                    jalali_to_gregorian(year, month as usize, day as i32);
+                }
+            }
+        }
+        // out-of-range days skip the check instead of tripping it
+        jalali_to_gregorian(1404, 12, 30);
+        jalali_to_gregorian(1404, 0, 0);
+    }
+
+    #[test]
+    fn test_jalali_last_friday_gregorian() {
+        assert_eq!(jalali_last_friday_gregorian(1404, 10), Some((2026, 1, 16)));
+
+        // Aban 1404 ends on Friday Aban 30, which is itself the last Friday
+        assert_eq!(jalali_last_friday_gregorian(1404, 8), Some((2025, 11, 21)));
+        assert_eq!(jalali_to_gregorian(1404, 8, 30), (2025, 11, 21));
+
+        assert_eq!(jalali_last_friday_gregorian(1404, 0), None);
+        assert_eq!(jalali_last_friday_gregorian(1404, 13), None);
+    }
+
+    #[test]
+    fn test_gregorian_to_jalali_via_jdn() {
+        // every day from 1900 to 2100, plus each month's out-of-range neighbours
+        for year in 1900..=2100 {
+            for month in 0..=13 {
+                for day in 0..=32 {
+                    assert_eq!(
+                        gregorian_to_jalali(year, month, day),
+                        gregorian_to_jalali_via_jdn(year, month, day),
+                        "{}-{}-{}",
+                        year,
+                        month,
+                        day
+                    );
+                }
+            }
+        }
+        for year in [-5000, -1, 0, 1, 622, 9999] {
+            assert_eq!(gregorian_to_jalali(year, 3, 21), gregorian_to_jalali_via_jdn(year, 3, 21));
+        }
+    }
+
+    #[test]
+    fn test_jalali_week_strings() {
+        // Nowruz 1404 was a Friday, so week 1 is a single day and week 2 starts on Farvardin 2
+        assert_eq!(jalali_to_week_string(1404, 1, 1), "1404-W01");
+        assert_eq!(jalali_to_week_string(1404, 1, 2), "1404-W02");
+        assert_eq!(parse_jalali_week_string("1404-W01"), Some((1403, 12, 25)));
+
+        // a mid-year week round-trips through its Saturday
+        assert_eq!(jalali_to_week_string(1404, 6, 20), "1404-W26");
+        let saturday = parse_jalali_week_string("1404-W26").unwrap();
+        assert_eq!(jalali_weekday_index(saturday.0, saturday.1, saturday.2), 0);
+        assert_eq!(jalali_to_week_string(saturday.0, saturday.1, saturday.2), "1404-W26");
+        assert_eq!(parse_jalali_week_string("۱۴۰۴-W۲۶"), Some(saturday));
+
+        assert_eq!(jalali_to_week_string(1404, 12, 29), "1404-W53");
+        assert_eq!(parse_jalali_week_string("1404-W54"), None);
+        assert_eq!(parse_jalali_week_string("1404-W00"), None);
+        assert_eq!(parse_jalali_week_string("1404-41"), None);
+    }
+
+    #[test]
+    fn test_jalali_working_days_between() {
+        // Saturday Dey 6 to Friday Dey 19: 14 days with two Fridays
+        assert_eq!(jalali_working_days_between((1404, 10, 6), (1404, 10, 19)), 12);
+        // a single Friday, a single Saturday and a reversed range
+        assert_eq!(jalali_working_days_between((1404, 10, 5), (1404, 10, 5)), 0);
+        assert_eq!(jalali_working_days_between((1404, 10, 6), (1404, 10, 6)), 1);
+        assert_eq!(jalali_working_days_between((1404, 10, 19), (1404, 10, 6)), 0);
+
+        // the closed form agrees with counting day by day across a year boundary
+        let (from, to) = ((1403, 11, 17), (1404, 2, 3));
+        assert_eq!(jalali_working_days_between(from, to), jalali_working_days_between_with(from, to, |_| false));
+
+        // Nowruz week: Farvardin 1 is a Friday, 2-4 and 12-13 are holidays, 8 is a Friday
+        let holidays = |(_, month, day): (i32, u32, u32)| month == 1 && matches!(day, 1..=4 | 12 | 13);
+        assert_eq!(jalali_working_days_between_with((1404, 1, 1), (1404, 1, 14), holidays), 7);
+    }
+
+    #[test]
+    fn test_parse_jalali_string_to_gregorian_string_detailed() {
+        let parse = parse_jalali_string_to_gregorian_string_detailed;
+        assert_eq!(parse("1400/12/10", '/'), Ok("2022-03-01".to_string()));
+        assert_eq!(parse("۱۴۰۰/۱۲/۱۰", '/'), Ok("2022-03-01".to_string()));
+        // the index of the bad digit, counted in characters even when the input is Persian
+        assert_eq!(parse("1400/12/1a", '/'), Err((JalaliError::InvalidDigit, 9)));
+        assert_eq!(parse("۱۴۰۰/۱۲/۱٠x", '/'), Err((JalaliError::InvalidDigit, 10)));
+        assert_eq!(parse("14o0/12/10", '/'), Err((JalaliError::InvalidDigit, 2)));
+        assert_eq!(parse("1400/12", '/'), Err((JalaliError::InvalidFormat, 7)));
+        assert_eq!(parse("1400/12/10/1", '/'), Err((JalaliError::InvalidFormat, 10)));
+        assert_eq!(parse("1400//10", '/'), Err((JalaliError::InvalidFormat, 5)));
+        assert_eq!(parse("1400/13/10", '/'), Err((JalaliError::InvalidMonth, 5)));
+        assert_eq!(parse("1403/12/31", '/'), Err((JalaliError::InvalidDay, 8)));
+        // every success agrees with the simple parser
+        for input in ["1403/12/30", "-5/1/1", "1,404/7/1", "1404/06/31"] {
+            assert_eq!(parse(input, '/').ok(), parse_jalali_string_to_gregorian_string(input, '/'));
+        }
+    }
+
+    #[test]
+    fn test_jalali_decade_and_century() {
+        assert_eq!(jalali_decade(1404), 1400);
+        assert_eq!(jalali_decade(1400), 1400);
+        assert_eq!(jalali_decade(1409), 1400);
+        assert_eq!(jalali_decade(0), 0);
+        assert_eq!(jalali_decade(-1), -10);
+        assert_eq!(jalali_decade(-10), -10);
+        assert_eq!(jalali_decade(-11), -20);
+        assert_eq!(jalali_decade(i32::MAX), 2_147_483_640);
+        assert_eq!(jalali_decade(i32::MIN + 8), -2_147_483_640);
+        assert_eq!(jalali_decade(i32::MIN), i32::MIN);
+        assert_eq!(jalali_century(1404), 14);
+        assert_eq!(jalali_century(1499), 14);
+        assert_eq!(jalali_century(99), 0);
+        assert_eq!(jalali_century(-1), -1);
+        assert_eq!(jalali_century(-100), -1);
+        assert_eq!(jalali_century(-101), -2);
+        assert_eq!(jalali_century(i32::MIN), -21_474_837);
+    }
+
+    #[test]
+    fn test_jalali_to_unix_day_based() {
+        assert_eq!(jalali_to_unix(1348, 10, 11), Some(0));
+        assert_eq!(jalali_to_unix(1348, 10, 10), Some(-86_400));
+        assert_eq!(jalali_to_unix(1404, 10, 6), Some(1_766_793_600));
+        // the furthest dates used to overflow the Gregorian year; now they are exact, not wrapped
+        let far_future = jalali_to_unix(i32::MAX, 12, 29).unwrap();
+        assert!(far_future > 0);
+        assert_eq!(far_future % 86_400, 0);
+        assert_eq!(jalali_to_unix(i32::MAX, 12, 28), Some(far_future - 86_400));
+        let far_past = jalali_to_unix(i32::MIN, 1, 1).unwrap();
+        assert!(far_past < 0);
+        assert_eq!(jalali_to_unix(i32::MIN, 1, 2), Some(far_past + 86_400));
+        // the day count never gets near i64::MAX / 86_400, so the checked product always fits
+        assert!(jalali_to_epoch_days(i32::MAX, 12, 31) < i64::MAX / 86_400);
+        assert!(jalali_to_unix(i32::MAX, u32::MAX, u32::MAX).is_some());
+        assert!(jalali_to_unix(i32::MIN, 1, 1).is_some());
+        // agrees with the timestamp-to-date direction
+        for ts in [0_i64, 86_399, 1_766_806_014] {
+            let (jy, jm, jd) = unix_to_jalali(ts).unwrap();
+            assert_eq!(jalali_to_unix(jy, jm, jd), Some(ts.div_euclid(86_400) * 86_400));
+        }
+    }
+
+    #[test]
+    fn test_jalali_month_phase() {
+        assert_eq!(jalali_month_phase(1), MonthPhase::Early);
+        assert_eq!(jalali_month_phase(10), MonthPhase::Early);
+        assert_eq!(jalali_month_phase(11), MonthPhase::Mid);
+        assert_eq!(jalali_month_phase(20), MonthPhase::Mid);
+        assert_eq!(jalali_month_phase(21), MonthPhase::Late);
+        assert_eq!(jalali_month_phase(29), MonthPhase::Late);
+        assert_eq!(jalali_month_phase(31), MonthPhase::Late);
+        assert_eq!(jalali_month_phase(0), MonthPhase::Early);
+    }
+
+    #[test]
+    fn test_jalali_day_of_season() {
+        assert_eq!(jalali_day_of_season(1404, 4, 1), 1);
+        assert_eq!(jalali_day_of_season(1404, 1, 1), 1);
+        assert_eq!(jalali_day_of_season(1404, 7, 1), 1);
+        assert_eq!(jalali_day_of_season(1404, 10, 1), 1);
+        assert_eq!(jalali_day_of_season(1404, 2, 1), 32);
+        assert_eq!(jalali_day_of_season(1404, 6, 31), 93);
+        assert_eq!(jalali_day_of_season(1404, 9, 30), 90);
+        assert_eq!(jalali_day_of_season(1404, 12, 29), 89);
+        assert_eq!(jalali_day_of_season(1403, 12, 30), 90);
+        assert_eq!(jalali_day_of_season(1404, 0, 1), 0);
+        assert_eq!(jalali_day_of_season(1404, 13, 1), 0);
+        assert_eq!(jalali_day_of_season(1404, 5, 0), 0);
+        // days past the end of the month would otherwise count into the next one
+        assert_eq!(jalali_day_of_season(1404, 7, 40), 0);
+        assert_eq!(jalali_day_of_season(1404, 7, 31), 0);
+        assert_eq!(jalali_day_of_season(1404, 12, 30), 0);
+    }
+
+    #[test]
+    fn test_jalali_conversion_table() {
+        assert_eq!(JalaliConversionTable::new(1405, 1404), None);
+        let table = JalaliConversionTable::new(1380, 1430).unwrap();
+        // every day of the window matches the scalar functions in both directions
+        for year in 1380..=1430 {
+            for month in 1..=12 {
+                for day in 1..=31 {
+                    let scalar = jalali_to_gregorian(year, month as usize, day as i32);
+                    let valid = gregorian_to_jalali(scalar.0, scalar.1 as usize, scalar.2 as i32) == (year, month, day);
+                    let fast = table.to_gregorian(year, month, day);
+                    assert_eq!(fast, valid.then_some(scalar), "{}-{}-{}", year, month, day);
+                    if valid {
+                        assert_eq!(table.from_gregorian(scalar.0, scalar.1, scalar.2), Some((year, month, day)));
+                    }
+                }
+            }
+        }
+        // the edges of the window
+        assert_eq!(table.to_gregorian(1379, 12, 29), None);
+        assert_eq!(table.to_gregorian(1431, 1, 1), None);
+        assert_eq!(table.from_gregorian(2001, 3, 20), None);
+        assert_eq!(table.from_gregorian(2001, 3, 21), Some((1380, 1, 1)));
+        assert_eq!(table.from_gregorian(2052, 3, 19), Some((1430, 12, 29)));
+        assert_eq!(table.from_gregorian(2052, 3, 20), None);
+        // a single-year window, also before year 0
+        let single = JalaliConversionTable::new(-5, -5).unwrap();
+        let nowruz = jalali_to_gregorian(-5, 1, 1);
+        assert_eq!(single.to_gregorian(-5, 1, 1), Some(nowruz));
+        assert_eq!(single.from_gregorian(nowruz.0, nowruz.1, nowruz.2), Some((-5, 1, 1)));
+        assert_eq!(single.to_gregorian(-4, 1, 1), None);
+        // invalid inputs
+        assert_eq!(table.to_gregorian(1404, 0, 1), None);
+        assert_eq!(table.to_gregorian(1404, 7, 31), None);
+        assert_eq!(table.from_gregorian(2025, 13, 1), None);
+        assert_eq!(table.from_gregorian(2025, 4, 31), None);
+    }
+
+    #[test]
+    fn test_nowruz_in_gregorian_year() {
+        assert_eq!(nowruz_in_gregorian_year(2025), (2025, 3, 21));
+        assert_eq!(nowruz_in_gregorian_year(2024), (2024, 3, 20));
+        assert_eq!(nowruz_in_gregorian_year(622), (622, 3, 21));
+        // one Nowruz per Gregorian year, always in late March across the historical range
+        for gregorian_year in 1..4000 {
+            let (year, month, day) = nowruz_in_gregorian_year(gregorian_year);
+            assert_eq!((year, month), (gregorian_year, 3));
+            assert!((20..=22).contains(&day), "{}: {}", gregorian_year, day);
+            assert_eq!(gregorian_to_jalali(year, month as usize, day as i32), (gregorian_year - 621, 1, 1));
+        }
+    }
+
+    #[test]
+    fn test_jalali_year_fraction() {
+        assert_eq!(jalali_year_fraction(1404, 1, 1), Some(0.0));
+        assert_eq!(jalali_year_fraction(1404, 7, 1), Some(186.0 / 365.0));
+        assert_eq!(jalali_year_fraction(1404, 12, 29), Some(364.0 / 365.0));
+        assert_eq!(jalali_year_fraction(1403, 12, 30), Some(365.0 / 366.0));
+        assert!(jalali_year_fraction(1403, 12, 30).unwrap() < 1.0);
+        assert_eq!(jalali_year_fraction(1404, 12, 30), None);
+        assert_eq!(jalali_year_fraction(1404, 13, 1), None);
+        assert_eq!(jalali_year_fraction(1404, 1, 0), None);
+    }
+
+    #[test]
+    fn test_jalali_days_since() {
+        // 1766806014 is 2025-12-27 03:26:54 UTC, Dey 6 1404
+        let now = 1766806014;
+        assert_eq!(jalali_days_since_from(1404, 10, 6, now), Some(0));
+        assert_eq!(jalali_days_since_from(1404, 10, 5, now), Some(1));
+        assert_eq!(jalali_days_since_from(1404, 1, 1, now), Some(281));
+        assert_eq!(jalali_days_since_from(1403, 10, 6, now), Some(366)); // 1403 has Esfand 30
+        assert_eq!(jalali_days_since_from(1404, 10, 7, now), None);
+        assert_eq!(jalali_days_since_from(1404, 12, 30, now), None);
+        // the day changes at midnight UTC
+        assert_eq!(jalali_days_since_from(1404, 10, 6, 1766793600 - 1), None);
+        assert_eq!(jalali_days_since_from(1404, 10, 6, 1766793600 + 86_399), Some(0));
+        assert_eq!(jalali_days_since_from(1404, 10, 6, 1766793600 + 86_400), Some(1));
+        // the clock-reading variant agrees for a date far in the past
+        assert!(jalali_days_since(1348, 10, 11).unwrap() > 20_000);
+    }
+
+    #[test]
+    fn test_julian_calendar() {
+        // the Gregorian reform: Thursday 1582-10-04 Julian was followed by Friday 1582-10-15 Gregorian
+        assert_eq!(julian_to_jdn(1582, 10, 4) + 1, gregorian_to_jdn(1582, 10, 15));
+        assert_eq!(jdn_to_julian(gregorian_to_jdn(1582, 10, 15)), (1582, 10, 5));
+        // before 1582 the same Jalali date lands on different days in the two calendars
+        assert_eq!(jalali_to_julian(1, 1, 1), (622, 3, 18));
+        assert_eq!(jalali_to_gregorian(1, 1, 1), (622, 3, 21));
+        assert_eq!(julian_to_jalali(622, 3, 18), (1, 1, 1));
+        assert_eq!(jalali_to_julian(700, 1, 1), jdn_to_julian(jalali_to_jdn(700, 1, 1)));
+        // the calendars agree in the 3rd century and drift by one day per century without a 400 leap
+        assert_eq!(julian_to_jdn(250, 3, 1), gregorian_to_jdn(250, 3, 1));
+        assert_eq!(julian_to_jdn(1900, 3, 1) - gregorian_to_jdn(1900, 3, 1), 13);
+        // Julian leap years: every fourth year, including 1900 and year 0 (1 BCE)
+        assert_eq!(jdn_to_julian(julian_to_jdn(1900, 2, 29)), (1900, 2, 29));
+        assert_eq!(jdn_to_julian(julian_to_jdn(0, 2, 29)), (0, 2, 29));
+        assert_eq!(jdn_to_julian(julian_to_jdn(-1, 2, 29)), (-1, 3, 1));
+        // round-trips day by day, including before JDN 0 (4713 BCE)
+        for jdn in (-1_000_000..3_000_000).step_by(97) {
+            let (year, month, day) = jdn_to_julian(jdn);
+            assert_eq!(julian_to_jdn(year, month, day), jdn);
+            assert_eq!(julian_to_jalali(year, month, day), jdn_to_jalali(jdn));
+        }
+    }
+
+    #[test]
+    fn test_jalali_holidays_in_range() {
+        let holidays = jalali_holidays_in_range((1403, 12, 25), (1404, 1, 5));
+        assert_eq!(
+            holidays,
+            vec![
+                ((1403, 12, 29), "ملی شدن صنعت نفت"),
+                ((1404, 1, 1), "نوروز"),
+                ((1404, 1, 2), "نوروز"),
+                ((1404, 1, 3), "نوروز"),
+                ((1404, 1, 4), "نوروز"),
+            ]
+        );
+        // a whole year has every fixed holiday once
+        assert_eq!(jalali_holidays_in_range((1404, 1, 1), (1404, 12, 29)).len(), 10);
+        assert!(jalali_holidays_in_range((1404, 5, 1), (1404, 5, 31)).is_empty());
+        assert!(jalali_holidays_in_range((1404, 1, 5), (1404, 1, 1)).is_empty());
+    }
+
+    #[test]
+    fn test_is_jalali_leap_year() {
+        for year in [1375, 1379, 1383, 1387, 1391, 1395, 1399, 1403, 1408, 1412] {
+            assert!(is_jalali_leap_year(year), "{}", year);
+        }
+        for year in [1400, 1401, 1402, 1404, 1405, 1406, 1407, 1409] {
+            assert!(!is_jalali_leap_year(year), "{}", year);
+        }
+        // negative years and the extremes follow the cycle without overflowing
+        assert!(is_jalali_leap_year(-11));
+        assert!(!is_jalali_leap_year(-1));
+        assert_eq!(is_jalali_leap_year(i32::MAX), jalali_month_length(i32::MAX, 12) == 30);
+        assert_eq!(is_jalali_leap_year(i32::MIN), jalali_month_length(i32::MIN, 12) == 30);
+        // agrees with the length of the year in the conversion math
+        for year in -2000..3000 {
+            let length = jalali_to_jdn(year + 1, 1, 1) - jalali_to_jdn(year, 1, 1);
+            assert_eq!(is_jalali_leap_year(year), length == 366, "{}", year);
+        }
+    }
+
+    #[test]
+    fn test_is_gregorian_leap_year() {
+        assert!(is_gregorian_leap_year(2000));
+        assert!(is_gregorian_leap_year(2024));
+        assert!(!is_gregorian_leap_year(1900));
+        assert!(!is_gregorian_leap_year(2025));
+        assert!(!is_gregorian_leap_year(2100));
+        // proleptic years: 0 is 1 BCE, -4 is 5 BCE
+        assert!(is_gregorian_leap_year(0));
+        assert!(is_gregorian_leap_year(-4));
+        assert!(!is_gregorian_leap_year(-1));
+        assert!(!is_gregorian_leap_year(-100));
+        assert!(is_gregorian_leap_year(-400));
+        // agrees with the length of February in the conversion math
+        for year in -1000..3000 {
+            let february = gregorian_to_jdn(year, 3, 1) - gregorian_to_jdn(year, 2, 1);
+            assert_eq!(is_gregorian_leap_year(year), february == 29, "{}", year);
+        }
+    }
+
+    #[test]
+    fn test_jalali_days_in_month() {
+        assert_eq!(jalali_days_in_month(1403, 12), Some(30));
+        assert_eq!(jalali_days_in_month(1404, 12), Some(29));
+        assert_eq!(jalali_days_in_month(1404, 1), Some(31));
+        assert_eq!(jalali_days_in_month(1404, 6), Some(31));
+        assert_eq!(jalali_days_in_month(1404, 7), Some(30));
+        assert_eq!(jalali_days_in_month(1404, 11), Some(30));
+        assert_eq!(jalali_days_in_month(1404, 0), None);
+        assert_eq!(jalali_days_in_month(1404, 13), None);
+        // the month lengths add up to the length of the year
+        for year in 1390..1420 {
+            let total: u32 = (1..=12).filter_map(|month| jalali_days_in_month(year, month)).sum();
+            assert_eq!(total, if is_jalali_leap_year(year) { 366 } else { 365 });
+        }
+    }
+
+    #[test]
+    fn test_jalali_to_unix_end_of_day() {
+        assert_eq!(jalali_to_unix_end_of_day(1348, 10, 11), Some(86_399));
+        assert_eq!(jalali_to_unix_end_of_day(1348, 10, 10), Some(-1));
+        for (year, month, day) in [(1404, 10, 6), (1403, 12, 30), (1, 1, 1), (i32::MAX, 12, 29)] {
+            let midnight = jalali_to_unix(year, month, day).unwrap();
+            assert_eq!(jalali_to_unix_end_of_day(year, month, day), Some(midnight + 86_399));
+        }
+        // the last second still belongs to the same Jalali day
+        let deadline = jalali_to_unix_end_of_day(1404, 10, 6).unwrap();
+        assert_eq!(unix_to_jalali(deadline), Some((1404, 10, 6)));
+        assert_eq!(unix_to_jalali(deadline + 1), Some((1404, 10, 7)));
+    }
+
+    #[test]
+    fn test_gregorian_days_in_month() {
+        assert_eq!(gregorian_days_in_month(2024, 2), Some(29));
+        assert_eq!(gregorian_days_in_month(2025, 2), Some(28));
+        assert_eq!(gregorian_days_in_month(1900, 2), Some(28));
+        assert_eq!(gregorian_days_in_month(2000, 2), Some(29));
+        assert_eq!(gregorian_days_in_month(2025, 1), Some(31));
+        assert_eq!(gregorian_days_in_month(2025, 4), Some(30));
+        assert_eq!(gregorian_days_in_month(2025, 12), Some(31));
+        assert_eq!(gregorian_days_in_month(2025, 0), None);
+        assert_eq!(gregorian_days_in_month(2025, 13), None);
+        // agrees with the JDN arithmetic
+        for year in [-401, -400, 0, 1582, 1900, 2024, 2025] {
+            for month in 1..=12 {
+                let length = gregorian_to_jdn(year, month as i32 + 1, 1) - gregorian_to_jdn(year, month as i32, 1);
+                assert_eq!(gregorian_days_in_month(year, month), Some(length as u32), "{}-{}", year, month);
+            }
+        }
     }
 
     #[test]
-    fn test_unix_to_jalali() {
-        let result = unix_to_jalali(0);
-        assert_eq!(result, Some((1348, 10, 11)));
+    fn test_gregorian_weekday_names() {
+        assert_eq!(gregorian_weekday_names(2025, 12, 27), ("شنبه", "Saturday"));
+        assert_eq!(gregorian_weekday_names(2025, 3, 21), ("جمعه", "Friday"));
+        assert_eq!(gregorian_weekday_names(1970, 1, 1), ("پنجشنبه", "Thursday"));
+        assert_eq!(gregorian_weekday_names(2000, 2, 29), ("سه‌شنبه", "Tuesday"));
+        // the names match the Jalali weekday of the same day
+        for day in 1..=31 {
+            let (jy, jm, jd) = gregorian_to_jalali(2026, 1, day);
+            let weekday = jalali_weekday_index(jy, jm, jd) as usize;
+            assert_eq!(gregorian_weekday_names(2026, 1, day as u32).0, WEEKDAY_NAMES[weekday]);
+        }
+    }
 
-        let result2 = unix_to_jalali(-1234567890);
-        assert_eq!(result2, Some((1309, 8, 28)));
+    #[test]
+    fn test_unix_to_tehran_day_start() {
+        // Tehran midnight starting Dey 6 1404 is 2025-12-26 20:30:00 UTC
+        let midnight = 1766781000;
+        assert_eq!(unix_to_tehran_day_start(midnight), Some(midnight));
+        assert_eq!(unix_to_tehran_day_start(midnight + 1), Some(midnight));
+        assert_eq!(unix_to_tehran_day_start(midnight + 86_399), Some(midnight));
+        assert_eq!(unix_to_tehran_day_start(midnight + 86_400), Some(midnight + 86_400));
+        // one second before Tehran midnight still belongs to Dey 5, although UTC is past 20:30
+        assert_eq!(unix_to_tehran_day_start(midnight - 1), Some(midnight - 86_400));
+        // UTC midnight is already 03:30 in Tehran
+        assert_eq!(unix_to_tehran_day_start(1766793600), Some(midnight));
+        assert_eq!(unix_to_tehran_day_start(0), Some(-12_600));
+        assert_eq!(unix_to_tehran_day_start(-12_601), Some(-12_600 - 86_400));
+        assert_eq!(unix_to_tehran_day_start(i64::MAX), None);
+        assert_eq!(unix_to_tehran_day_start(i64::MIN), None);
     }
 
     #[test]
-    fn test_jalali_to_unix() {
-        let result = jalali_to_unix(1348, 10, 11);
-        assert_eq!(result, Some(0));
+    fn test_is_valid_jalali_date() {
+        assert!(is_valid_jalali_date(1404, 1, 1));
+        assert!(is_valid_jalali_date(1404, 6, 31));
+        assert!(is_valid_jalali_date(1404, 7, 30));
+        assert!(!is_valid_jalali_date(1404, 7, 31));
+        assert!(!is_valid_jalali_date(1404, 1, 0));
+        assert!(!is_valid_jalali_date(1404, 0, 1));
+        assert!(!is_valid_jalali_date(1404, 13, 1));
+        // Esfand 30 exists only in leap years
+        assert!(is_valid_jalali_date(1403, 12, 30));
+        assert!(!is_valid_jalali_date(1404, 12, 30));
+        assert!(is_valid_jalali_date(1408, 12, 30));
+        assert!(!is_valid_jalali_date(1407, 12, 30));
+        // the parser rejects what the validator rejects
+        assert_eq!(parse_jalali_string_to_gregorian_string("1403/12/30", '/'), Some("2025-03-20".to_string()));
+        assert_eq!(parse_jalali_string_to_gregorian_string("1404/12/30", '/'), None);
+        assert_eq!(parse_jalali_string_to_gregorian_string("1404/07/31", '/'), None);
+        assert_eq!(parse_jalali_string_to_gregorian_string("1404/07/-1", '/'), None);
     }
 
     #[test]
-    fn test_parse_gregorian_string_to_jalali_string() {
-        let result = parse_gregorian_string_to_jalali_string("2025-12-27", '-');
-        assert_eq!(result, Some("1404-10-06".to_string()));
+    fn test_convert() {
+        // one day in every calendar: Tir 6 1404 = 27 June 2025 = 14 June 2025 Julian = 1 Muharram 1447
+        let day = [
+            (Calendar::Jalali, (1404, 4, 6)),
+            (Calendar::Gregorian, (2025, 6, 27)),
+            (Calendar::Julian, (2025, 6, 14)),
+            (Calendar::Hijri, (1447, 1, 1)),
+        ];
+        for (from, (y, m, d)) in day {
+            for (to, expected) in day {
+                assert_eq!(convert(from, to, y, m, d), Some(expected), "{:?} -> {:?}", from, to);
+            }
+        }
+        // the pairs agree with the dedicated functions over a long span
+        for jdn in (1_948_440..2_500_000).step_by(101) {
+            let (jy, jm, jd) = jdn_to_jalali(jdn);
+            let gregorian = jalali_to_gregorian(jy, jm as usize, jd as i32);
+            assert_eq!(convert(Calendar::Jalali, Calendar::Gregorian, jy, jm, jd), Some(gregorian));
+            assert_eq!(convert(Calendar::Jalali, Calendar::Julian, jy, jm, jd), Some(jalali_to_julian(jy, jm, jd)));
+            let hijri = jalali_to_hijri(jy, jm, jd).unwrap();
+            assert_eq!(convert(Calendar::Hijri, Calendar::Jalali, hijri.0, hijri.1, hijri.2), Some((jy, jm, jd)));
+        }
+        // invalid dates in each calendar are rejected, not rolled over
+        assert_eq!(convert(Calendar::Jalali, Calendar::Gregorian, 1404, 12, 30), None);
+        assert_eq!(convert(Calendar::Gregorian, Calendar::Jalali, 2025, 2, 29), None);
+        assert_eq!(convert(Calendar::Gregorian, Calendar::Jalali, 2025, 13, 1), None);
+        assert_eq!(convert(Calendar::Julian, Calendar::Jalali, 1900, 2, 30), None);
+        assert_eq!(convert(Calendar::Julian, Calendar::Gregorian, 1900, 2, 29), Some((1900, 3, 13)));
+        assert_eq!(convert(Calendar::Hijri, Calendar::Jalali, 1447, 2, 30), None);
+        assert_eq!(convert(Calendar::Hijri, Calendar::Jalali, 0, 1, 1), None);
+        // results that can't be represented
+        assert_eq!(convert(Calendar::Jalali, Calendar::Hijri, 1, 1, 1), None);
+        assert_eq!(convert(Calendar::Jalali, Calendar::Gregorian, i32::MAX, 1, 1), None);
+    }
 
-        let result_persian = parse_gregorian_string_to_jalali_string("۲۰۲۵-۱۲-۲۷", '-');
-        assert_eq!(result_persian, Some("1404-10-06".to_string()));
+    #[test]
+    fn test_is_valid_gregorian_date() {
+        assert!(is_valid_gregorian_date(2024, 2, 29));
+        assert!(!is_valid_gregorian_date(2023, 2, 29));
+        assert!(!is_valid_gregorian_date(2025, 2, 30));
+        assert!(is_valid_gregorian_date(2000, 2, 29));
+        assert!(!is_valid_gregorian_date(1900, 2, 29));
+        assert!(is_valid_gregorian_date(2025, 12, 31));
+        assert!(!is_valid_gregorian_date(2025, 4, 31));
+        assert!(!is_valid_gregorian_date(2025, 1, 0));
+        assert!(!is_valid_gregorian_date(2025, 0, 1));
+        assert!(!is_valid_gregorian_date(2025, 13, 1));
+        // the parser rejects what the validator rejects
+        assert_eq!(parse_gregorian_string_to_jalali_string("2024-02-29", '-'), Some("1402-12-10".to_string()));
+        assert_eq!(parse_gregorian_string_to_jalali_string("2023-02-29", '-'), None);
+        assert_eq!(parse_gregorian_string_to_jalali_string("2025-02-30", '-'), None);
+        assert_eq!(parse_gregorian_string_to_jalali_string("2025-02-00", '-'), None);
+    }
 
-        let invalid = parse_gregorian_string_to_jalali_string("invalid", '-');
-        assert_eq!(invalid, None);
+    #[test]
+    fn test_jalali_start_of_adjacent_months() {
+        assert_eq!(jalali_start_of_next_month(1404, 1), (1404, 2, 1));
+        assert_eq!(jalali_start_of_next_month(1404, 6), (1404, 7, 1));
+        assert_eq!(jalali_start_of_next_month(1404, 12), (1405, 1, 1));
+        assert_eq!(jalali_start_of_next_month(-1, 12), (0, 1, 1));
+        assert_eq!(jalali_start_of_previous_month(1404, 7), (1404, 6, 1));
+        assert_eq!(jalali_start_of_previous_month(1404, 1), (1403, 12, 1));
+        assert_eq!(jalali_start_of_previous_month(0, 1), (-1, 12, 1));
+        // the next month starts the day after the current month ends
+        for month in 1..=12 {
+            let (year, next, day) = jalali_start_of_next_month(1403, month);
+            let last = jalali_to_jdn(1403, month, jalali_month_length(1403, month));
+            assert_eq!(jalali_to_jdn(year, next, day), last + 1);
+            assert_eq!(jalali_start_of_previous_month(year, next), (1403, month, 1));
+        }
     }
 
     #[test]
-    fn test_parse_jalali_string_to_gregorian_string() {
-        let result = parse_jalali_string_to_gregorian_string("1404-10-06", '-');
-        assert_eq!(result, Some("2025-12-27".to_string()));
+    fn test_jalali_date() {
+        let date = JalaliDate::new(1404, 10, 6).unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (1404, 10, 6));
+        assert_eq!(date.to_gregorian(), (2025, 12, 27));
+        assert_eq!(JalaliDate::from_gregorian(2025, 12, 27), Some(date));
+        // the constructors reject dates that don't exist
+        assert!(JalaliDate::new(1403, 12, 30).is_some());
+        assert_eq!(JalaliDate::new(1404, 12, 30), None);
+        assert_eq!(JalaliDate::new(1404, 7, 31), None);
+        assert_eq!(JalaliDate::new(1404, 13, 1), None);
+        assert_eq!(JalaliDate::new(1404, 1, 0), None);
+        assert_eq!(JalaliDate::from_gregorian(2025, 2, 29), None);
+        assert_eq!(JalaliDate::from_gregorian(i32::MIN, 1, 1), None);
+        // the methods agree with the free functions
+        for (gy, gm, gd) in [(2024, 2, 29), (2025, 3, 20), (2025, 3, 21), (622, 3, 21), (-100, 7, 4)] {
+            let date = JalaliDate::from_gregorian(gy, gm, gd).unwrap();
+            let expected = gregorian_to_jalali(gy, gm as usize, gd as i32);
+            assert_eq!((date.year(), date.month(), date.day()), expected);
+            assert_eq!(date.to_gregorian(), (gy, gm, gd));
+        }
+    }
 
-        let result_persian = parse_jalali_string_to_gregorian_string("۱۴۰۴-۱۰-۰۶", '-');
-        assert_eq!(result_persian, Some("2025-12-27".to_string()));
+    #[test]
+    fn test_jalali_add_days_with_flags() {
+        assert_eq!(jalali_add_days_with_flags(1404, 10, 6, 3), ((1404, 10, 9), false, false));
+        assert_eq!(jalali_add_days_with_flags(1404, 10, 29, 3), ((1404, 11, 2), true, false));
+        assert_eq!(jalali_add_days_with_flags(1404, 12, 29, 1), ((1405, 1, 1), true, true));
+        assert_eq!(jalali_add_days_with_flags(1404, 1, 1, -1), ((1403, 12, 30), true, true));
+        assert_eq!(jalali_add_days_with_flags(1404, 10, 6, 0), ((1404, 10, 6), false, false));
+        // same month number a year later still flags both
+        assert_eq!(jalali_add_days_with_flags(1404, 10, 6, 365), ((1405, 10, 6), true, true));
+    }
 
-        let invalid = parse_jalali_string_to_gregorian_string("invalid", '-');
-        assert_eq!(invalid, None);
+    #[test]
+    fn test_jalali_date_display() {
+        let date = JalaliDate::new(1404, 10, 6).unwrap();
+        assert_eq!(date.to_string(), "1404-10-06");
+        assert_eq!(format!("[{:>12}]", date), "[  1404-10-06]");
+        assert_eq!(format!("[{:*<12}]", date), "[1404-10-06**]");
+        assert_eq!(JalaliDate::new(5, 1, 1).unwrap().to_string(), "0005-01-01");
+        // the output parses back to the same day
+        let gregorian = parse_jalali_string_to_gregorian_string(&date.to_string(), '-').unwrap();
+        assert_eq!(gregorian, "2025-12-27");
+        assert_eq!(parse_gregorian_string_to_jalali_string(&gregorian, '-').unwrap(), date.to_string());
     }
 
     #[test]
-    fn test_latin_digits_to_persian() {
-        let result = latin_digits_to_persian("1400-12-10");
-        assert_eq!(result, "۱۴۰۰-۱۲-۱۰");
+    fn test_parse_jalali_prefix() {
+        assert_eq!(parse_jalali_prefix("1404-10-06 rest of line"), Some(((1404, 10, 6), " rest of line")));
+        assert_eq!(parse_jalali_prefix("1404/1/6"), Some(((1404, 1, 6), "")));
+        assert_eq!(parse_jalali_prefix("۱۴۰۴/۱۰/۰۶، جلسه"), Some(((1404, 10, 6), "، جلسه")));
+        assert_eq!(parse_jalali_prefix("1404-10-06T12:30"), Some(((1404, 10, 6), "T12:30")));
+        // the date must be at the start, complete and existing
+        assert_eq!(parse_jalali_prefix(" 1404-10-06"), None);
+        assert_eq!(parse_jalali_prefix("date 1404-10-06"), None);
+        assert_eq!(parse_jalali_prefix("1404-10-061"), None);
+        assert_eq!(parse_jalali_prefix("1404-07-31 x"), None);
+        assert_eq!(parse_jalali_prefix("1404-10"), None);
+        assert_eq!(parse_jalali_prefix(""), None);
     }
 
     #[test]
-    fn test_persian_or_arabic_digits_to_latin() {
-        let result_persian = persian_or_arabic_digits_to_latin("۱۴۰۰-۱۲-۱۰");
-        assert_eq!(result_persian, "1400-12-10");
+    fn test_jalali_weekday() {
+        assert_eq!(jalali_weekday(1404, 1, 1), 6); // Friday 2025-03-21
+        assert_eq!(jalali_weekday(1404, 1, 2), 0); // Saturday
+        assert_eq!(jalali_weekday(1404, 10, 6), 0); // Saturday 2025-12-27
+        assert_eq!(jalali_weekday(1348, 10, 11), 5); // Thursday 1970-01-01
+        assert_eq!(jalali_weekday(1403, 12, 30), 5); // Thursday 2025-03-20
+        // consecutive days step through the week, across months and years
+        let mut previous = jalali_weekday(1403, 12, 1);
+        for jdn in jalali_to_jdn(1403, 12, 2)..jalali_to_jdn(1405, 2, 1) {
+            let (year, month, day) = jdn_to_jalali(jdn);
+            let weekday = jalali_weekday(year, month, day);
+            assert_eq!(weekday, (previous + 1) % 7);
+            previous = weekday;
+        }
+    }
 
-        let result_arabic = persian_or_arabic_digits_to_latin("١٤٠٠-١٢-١٠");
-        assert_eq!(result_arabic, "1400-12-10");
+    #[test]
+    fn test_jalali_add_weeks() {
+        // Dey has 30 days, so two weeks from Dey 20 is Bahman 4
+        assert_eq!(jalali_add_weeks(1404, 10, 20, 2), (1404, 11, 4));
+        assert_eq!(jalali_add_weeks(1404, 10, 20, 0), (1404, 10, 20));
+        assert_eq!(jalali_add_weeks(1404, 11, 4, -2), (1404, 10, 20));
+        assert_eq!(jalali_add_weeks(1404, 12, 25, 1), (1405, 1, 3));
+        let start = jalali_to_jdn(1404, 10, 20);
+        let (year, month, day) = jalali_add_weeks(1404, 10, 20, 2);
+        assert_eq!(jalali_to_jdn(year, month, day) - start, 14);
+        assert_eq!(jalali_weekday(year, month, day), jalali_weekday(1404, 10, 20));
+    }
 
-        let mixed = persian_or_arabic_digits_to_latin("۴٤۵٥۶٦"); // Persian 4, Arabic 4,5,6
-        assert_eq!(mixed, "445566");
+    #[test]
+    fn test_jalali_weekday_enum() {
+        assert_eq!(JalaliWeekday::from_index(0), Some(JalaliWeekday::Shanbe));
+        assert_eq!(JalaliWeekday::from_index(6), Some(JalaliWeekday::Jomeh));
+        assert_eq!(JalaliWeekday::from_index(7), None);
+        assert_eq!(JalaliWeekday::Shanbe.persian_name(), "شنبه");
+        assert_eq!(JalaliWeekday::Shanbe.english_name(), "Saturday");
+        assert_eq!(JalaliWeekday::Seshanbe.persian_name(), "سه‌شنبه");
+        assert_eq!(JalaliWeekday::Jomeh.english_name(), "Friday");
+        for index in 0..7 {
+            let weekday = JalaliWeekday::from_index(index).unwrap();
+            assert_eq!(weekday as u8, index);
+        }
+        assert!(JalaliWeekday::Shanbe < JalaliWeekday::Jomeh);
+        // the method agrees with the free function
+        assert_eq!(JalaliDate::new(1404, 1, 1).unwrap().weekday(), JalaliWeekday::Jomeh);
+        assert_eq!(JalaliDate::new(1348, 10, 11).unwrap().weekday(), JalaliWeekday::Panjshanbe);
+        let date = JalaliDate::from_gregorian(2025, 12, 27).unwrap();
+        assert_eq!(
+            (date.weekday().persian_name(), date.weekday().english_name()),
+            gregorian_weekday_names(2025, 12, 27)
+        );
+    }
+
+    #[test]
+    fn test_jalali_year_month_lengths() {
+        assert_eq!(jalali_year_month_lengths(1403)[11], 30);
+        assert_eq!(jalali_year_month_lengths(1404)[11], 29);
+        assert_eq!(jalali_year_month_lengths(1404), [31, 31, 31, 31, 31, 31, 30, 30, 30, 30, 30, 29]);
+        for year in [-12, 0, 1399, 1403, 1404, 1408] {
+            let lengths = jalali_year_month_lengths(year);
+            for month in 1..=12 {
+                assert_eq!(Some(lengths[month as usize - 1]), jalali_days_in_month(year, month));
+            }
+        }
+    }
+
+    #[test]
+    fn test_jalali_year_gregorian_span() {
+        assert_eq!(jalali_year_gregorian_span(1404), (2025, 2026));
+        assert_eq!(jalali_year_gregorian_span(1403), (2024, 2025));
+        assert_eq!(jalali_year_gregorian_span(1), (622, 623));
+        assert_eq!(jalali_year_gregorian_span(-621), (0, 1));
+    }
+
+    #[test]
+    fn test_jalali_month_names() {
+        assert_eq!(jalali_month_name(1), Some("فروردین"));
+        assert_eq!(jalali_month_name(10), Some("دی"));
+        assert_eq!(jalali_month_name(12), Some("اسفند"));
+        assert_eq!(jalali_month_name(0), None);
+        assert_eq!(jalali_month_name(13), None);
+        assert_eq!(jalali_month_name_en(1), Some("Farvardin"));
+        assert_eq!(jalali_month_name_en(2), Some("Ordibehesht"));
+        assert_eq!(jalali_month_name_en(10), Some("Dey"));
+        assert_eq!(jalali_month_name_en(12), Some("Esfand"));
+        assert_eq!(jalali_month_name_en(0), None);
+        assert_eq!(jalali_month_name_en(u32::MAX), None);
+    }
+
+    #[test]
+    fn test_day_past_end_of_jalali_month_is_rejected() {
+        // Bahman has 30 days; without validation day 31 would roll into Esfand 1
+        assert_eq!(jalali_to_gregorian(1404, 11, 31), jalali_to_gregorian(1404, 12, 1));
+        assert_eq!(try_jalali_to_gregorian(1404, 11, 30), Some((2026, 2, 19)));
+        assert_eq!(try_jalali_to_gregorian(1404, 11, 31), None);
+        // Esfand has 29 days in 1404 and 30 in the leap year 1403
+        assert_eq!(try_jalali_to_gregorian(1404, 12, 29), Some((2026, 3, 20)));
+        assert_eq!(try_jalali_to_gregorian(1404, 12, 30), None);
+        assert_eq!(try_jalali_to_gregorian(1404, 12, 31), None);
+        assert_eq!(try_jalali_to_gregorian(1403, 12, 30), Some((2025, 3, 20)));
+        assert_eq!(try_jalali_to_gregorian(1404, 0, 1), None);
+        assert_eq!(try_jalali_to_gregorian(1404, 1, 0), None);
+        // the string parsers reject the same days
+        for input in ["1404/11/31", "1404/12/30", "1404/07/31"] {
+            assert_eq!(parse_jalali_string_to_gregorian_string(input, '/'), None, "{}", input);
+            assert_eq!(
+                parse_jalali_string_to_gregorian_string_detailed(input, '/'),
+                Err((JalaliError::InvalidDay, 8)),
+                "{}", input
+            );
+        }
+        assert_eq!(parse_jalali_datetime_string_to_gregorian_string("1404-11-31 10:00", '-'), None);
+    }
+
+    #[test]
+    fn test_format_jalali() {
+        assert_eq!(format_jalali(1404, 10, 6, "%Y-%m-%d"), "1404-10-06");
+        assert_eq!(format_jalali(1404, 10, 6, "%d %B %Y"), "06 دی 1404");
+        assert_eq!(format_jalali(1404, 10, 6, "%A"), "شنبه");
+        assert_eq!(format_jalali(1404, 1, 1, "%j"), "001");
+        assert_eq!(format_jalali(1404, 10, 6, "%j"), "282");
+        assert_eq!(format_jalali(1403, 12, 30, "%j"), "366");
+        assert_eq!(format_jalali(5, 1, 1, "%Y"), "0005");
+        // literals, escapes and unknown tokens
+        assert_eq!(format_jalali(1404, 10, 6, "100%% on %Y"), "100% on 1404");
+        assert_eq!(format_jalali(1404, 10, 6, "%q %"), "%q %");
+        assert_eq!(format_jalali(1404, 10, 6, "تاریخ: %d/%m"), "تاریخ: 06/10");
+        assert_eq!(format_jalali(1404, 10, 6, ""), "");
+        // the default pattern matches the Display output
+        assert_eq!(format_jalali(1404, 10, 6, "%Y-%m-%d"), JalaliDate::new(1404, 10, 6).unwrap().to_string());
+    }
+
+    #[test]
+    fn test_jalali_add_days() {
+        assert_eq!(jalali_add_days(1403, 12, 30, 1), (1404, 1, 1));
+        assert_eq!(jalali_add_days(1404, 12, 29, 1), (1405, 1, 1));
+        assert_eq!(jalali_add_days(1404, 1, 1, -1), (1403, 12, 30));
+        assert_eq!(jalali_add_days(1405, 1, 1, -1), (1404, 12, 29));
+        assert_eq!(jalali_add_days(1404, 6, 31, 1), (1404, 7, 1));
+        assert_eq!(jalali_add_days(1404, 10, 6, 0), (1404, 10, 6));
+        assert_eq!(jalali_add_days(1404, 1, 1, 365), (1405, 1, 1));
+        assert_eq!(jalali_add_days(1403, 1, 1, 365), (1403, 12, 30));
+        assert_eq!(jalali_add_days(1404, 10, 6, -1000), (1402, 1, 13));
+        // the method stays a valid date
+        let date = JalaliDate::new(1403, 12, 30).unwrap();
+        assert_eq!(date.add_days(1), JalaliDate::new(1404, 1, 1).unwrap());
+        assert_eq!(date.add_days(1).add_days(-1), date);
+    }
+
+    #[test]
+    fn test_jalali_is_last_day_of_month() {
+        assert!(jalali_is_last_day_of_month(1404, 1, 31));
+        assert!(!jalali_is_last_day_of_month(1404, 1, 30));
+        assert!(jalali_is_last_day_of_month(1404, 7, 30));
+        assert!(!jalali_is_last_day_of_month(1404, 7, 29));
+        assert!(!jalali_is_last_day_of_month(1404, 7, 31));
+        // Esfand: 29 days in 1404, 30 in the leap year 1403
+        assert!(jalali_is_last_day_of_month(1404, 12, 29));
+        assert!(!jalali_is_last_day_of_month(1404, 12, 30));
+        assert!(jalali_is_last_day_of_month(1403, 12, 30));
+        assert!(!jalali_is_last_day_of_month(1403, 12, 29));
+        assert!(!jalali_is_last_day_of_month(1404, 13, 30));
+        assert!(!jalali_is_last_day_of_month(1404, 0, 31));
+    }
+
+    #[test]
+    fn test_jalali_diff_days() {
+        assert_eq!(jalali_diff_days((1404, 1, 1), (1403, 1, 1)), 366);
+        assert_eq!(jalali_diff_days((1405, 1, 1), (1404, 1, 1)), 365);
+        assert_eq!(jalali_diff_days((1403, 1, 1), (1404, 1, 1)), -366);
+        assert_eq!(jalali_diff_days((1404, 10, 6), (1404, 10, 6)), 0);
+        assert_eq!(jalali_diff_days((1404, 1, 1), (1403, 12, 30)), 1);
+        // a full 33-year cycle
+        assert_eq!(jalali_diff_days((1408, 1, 1), (1375, 1, 1)), 12053);
+        // inverse of jalali_add_days
+        for delta in [-10_000, -1, 0, 1, 400, 10_000] {
+            let later = jalali_add_days(1404, 10, 6, delta);
+            assert_eq!(jalali_diff_days(later, (1404, 10, 6)), delta);
+        }
+    }
+
+    #[test]
+    fn test_jalali_strings_to_sortable_ints() {
+        let rows = ["1404/10/06", "۱۴۰۳/۱۲/۳۰", "1404/12/30", "not a date", "1404/7/1", "", "1,404/01/01", "1,4,0,4/01/01"];
+        assert_eq!(
+            jalali_strings_to_sortable_ints(&rows, '/'),
+            vec![Some(14041006), Some(14031230), None, None, Some(14040701), None, Some(14040101), None]
+        );
+        assert_eq!(jalali_strings_to_sortable_ints(&["214748/12/29", "214749/01/01"], '/'), vec![Some(2147481229), None]);
+        assert!(jalali_strings_to_sortable_ints(&[], '/').is_empty());
+        // the keys sort in calendar order, also before year 0
+        let dates = [(-5, 1, 1), (-5, 12, 29), (-4, 1, 1), (0, 6, 31), (1403, 12, 30), (1404, 1, 1)];
+        let keys: Vec<i32> = dates.iter().map(|&(y, m, d)| jalali_to_sortable_int(y, m, d).unwrap()).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_jalali_date_ordering() {
+        let date = |year, month, day| JalaliDate::new(year, month, day).unwrap();
+        let mut dates = vec![
+            date(1404, 10, 6),
+            date(1403, 12, 30),
+            date(-1, 1, 1),
+            date(1404, 1, 1),
+            date(1404, 10, 5),
+            date(1, 1, 1),
+            date(1404, 9, 30),
+        ];
+        dates.sort();
+        assert_eq!(
+            dates,
+            vec![
+                date(-1, 1, 1),
+                date(1, 1, 1),
+                date(1403, 12, 30),
+                date(1404, 1, 1),
+                date(1404, 9, 30),
+                date(1404, 10, 5),
+                date(1404, 10, 6),
+            ]
+        );
+        // agrees with jalali_diff_days and with equality
+        for pair in dates.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert!(a < b);
+            assert!(jalali_diff_days((b.year(), b.month(), b.day()), (a.year(), a.month(), a.day())) > 0);
+        }
+        assert_eq!(date(1404, 10, 6).cmp(&date(1404, 10, 6)), std::cmp::Ordering::Equal);
+        assert_eq!(dates.iter().max(), Some(&date(1404, 10, 6)));
+    }
+
+    #[test]
+    fn test_jdn_to_gregorian_before_year_one() {
+        // reference dates in astronomical year numbering (year 0 is 1 BCE)
+        assert_eq!(jdn_to_gregorian(1_721_426), (1, 1, 1));
+        assert_eq!(jdn_to_gregorian(1_721_425), (0, 12, 31));
+        assert_eq!(jdn_to_gregorian(1_721_060), (0, 1, 1));
+        assert_eq!(jdn_to_gregorian(1_721_059), (-1, 12, 31));
+        assert_eq!(jdn_to_gregorian(0), (-4713, 11, 24));
+        assert_eq!(jdn_to_gregorian(-1), (-4713, 11, 23));
+        assert_eq!(jdn_to_gregorian(-32_045), (-4800, 2, 29));
+        assert_eq!(jdn_to_gregorian(-32_044), (-4800, 3, 1));
+        // agrees with the inverse and with the day-count conversion far before and after the epochs
+        for jdn in (-3_000_000..3_000_000).step_by(89) {
+            let (year, month, day) = jdn_to_gregorian(jdn);
+            assert_eq!(gregorian_to_jdn(year, month as i32, day as i32), jdn);
+            assert_eq!((year, month, day), days_to_gregorian(jdn - epoch::GREGORIAN_EPOCH_JDN + 366));
+        }
+    }
+
+    #[test]
+    fn test_jalali_date_from_str() {
+        let date = JalaliDate::new(1404, 10, 6).unwrap();
+        assert_eq!("1404-10-06".parse::<JalaliDate>(), Ok(date));
+        assert_eq!("۱۴۰۴-۱۰-۰۶".parse::<JalaliDate>(), Ok(date));
+        assert_eq!("١٤٠٤-١٠-٠٦".parse::<JalaliDate>(), Ok(date));
+        assert_eq!(date.to_string().parse::<JalaliDate>(), Ok(date));
+        assert_eq!("1404-12-30".parse::<JalaliDate>(), Err(JalaliError::InvalidDay));
+        assert_eq!("1404-13-01".parse::<JalaliDate>(), Err(JalaliError::InvalidMonth));
+        assert_eq!("1404/10/06".parse::<JalaliDate>(), Err(JalaliError::InvalidDigit));
+        assert_eq!("1404-10".parse::<JalaliDate>(), Err(JalaliError::InvalidFormat));
+    }
+
+    #[test]
+    fn test_gregorian_month_to_jalali() {
+        assert_eq!(gregorian_month_to_jalali(2025, 12), (1404, 9));
+        assert_eq!(gregorian_month_to_jalali(2025, 3), (1403, 12));
+        assert_eq!(gregorian_month_to_jalali(2025, 4), (1404, 1));
+        assert_eq!(gregorian_month_to_jalali(2026, 1), (1404, 10));
+        // the anchor is the Jalali month holding most days of the Gregorian month
+        for month in 1..=12 {
+            let (jy, jm) = gregorian_month_to_jalali(2025, month);
+            let days = gregorian_days_in_month(2025, month).unwrap();
+            let covered = (1..=days)
+                .filter(|&day| {
+                    let (y, m, _) = gregorian_to_jalali(2025, month as usize, day as i32);
+                    (y, m) == (jy, jm)
+                })
+                .count() as u32;
+            assert!(covered * 2 > days, "{}", month);
+        }
+    }
+
+    #[test]
+    fn test_today() {
+        let before = now_jalali().unwrap();
+        let today = today();
+        let after = now_jalali().unwrap();
+        // the clock may cross midnight between the calls, but never by more than a day
+        assert!(today == before || today == after);
+        assert!(is_valid_jalali_date(today.0, today.1, today.2));
+        assert!(today.0 >= 1404);
+    }
+
+    #[test]
+    fn test_jalali_day_unix_range() {
+        assert_eq!(jalali_day_unix_range(1348, 10, 11), Some((0, 86_400)));
+        let (start, end) = jalali_day_unix_range(1404, 10, 6).unwrap();
+        assert_eq!(start, 1766793600);
+        assert_eq!(end - start, 86_400);
+        assert_eq!(unix_to_jalali(start), Some((1404, 10, 6)));
+        assert_eq!(unix_to_jalali(end - 1), Some((1404, 10, 6)));
+        assert_eq!(unix_to_jalali(end), Some((1404, 10, 7)));
+        // consecutive days tile the timeline
+        assert_eq!(jalali_day_unix_range(1404, 10, 7).unwrap().0, end);
+        assert_eq!(jalali_day_unix_range(1404, 12, 30), None);
+        assert_eq!(jalali_day_unix_range(1404, 13, 1), None);
+
+        // the Tehran day starts at 20:30 UTC the evening before
+        let (start, end) = jalali_day_unix_range_tehran(1404, 10, 6).unwrap();
+        assert_eq!(end - start, 86_400);
+        assert_eq!(unix_to_tehran_day_start(start), Some(start));
+        assert_eq!(unix_to_tehran_day_start(end - 1), Some(start));
+        assert_eq!(jalali_day_unix_range_tehran(1404, 12, 30), None);
+    }
+
+    #[test]
+    fn test_jalali_weekday_numberings() {
+        // (date, Saturday-based, ISO, Sunday-based) for Saturday 2025-12-27 through Friday 2026-01-02
+        let week = [
+            ((1404, 10, 6), 0, 6, 6),
+            ((1404, 10, 7), 1, 7, 0),
+            ((1404, 10, 8), 2, 1, 1),
+            ((1404, 10, 9), 3, 2, 2),
+            ((1404, 10, 10), 4, 3, 3),
+            ((1404, 10, 11), 5, 4, 4),
+            ((1404, 10, 12), 6, 5, 5),
+        ];
+        for ((year, month, day), saturday_zero, iso, sunday_zero) in week {
+            assert_eq!(jalali_weekday(year, month, day), saturday_zero);
+            assert_eq!(jalali_weekday_iso(year, month, day), iso);
+            assert_eq!(jalali_weekday_sunday_zero(year, month, day), sunday_zero);
+        }
+        // Thursday 1970-01-01
+        assert_eq!(jalali_weekday(1348, 10, 11), 5);
+        assert_eq!(jalali_weekday_iso(1348, 10, 11), 4);
+        assert_eq!(jalali_weekday_sunday_zero(1348, 10, 11), 4);
+    }
+
+    #[test]
+    fn test_jalali_date_time() {
+        let date = JalaliDate::new(1404, 10, 6).unwrap();
+        assert!(JalaliDateTime::new(date, 23, 59, 59).is_some());
+        assert!(JalaliDateTime::new(date, 24, 0, 0).is_none());
+        assert!(JalaliDateTime::new(date, 0, 60, 0).is_none());
+        assert!(JalaliDateTime::new(date, 0, 0, 60).is_none());
+
+        let date_time = JalaliDateTime::from_unix(1766806014).unwrap();
+        assert_eq!(date_time, JalaliDateTime::new(date, 3, 26, 54).unwrap());
+        for timestamp in [0, 1, 1766806014, 1766879999, -1, -86_400, -86_401, -1_000_000_007] {
+            assert_eq!(JalaliDateTime::from_unix(timestamp).unwrap().to_unix(), Some(timestamp));
+        }
+
+        // one second before the Unix epoch is the last second of 1348-10-10
+        let before_epoch = JalaliDateTime::from_unix(-1).unwrap();
+        assert_eq!(before_epoch.date(), JalaliDate::new(1348, 10, 10).unwrap());
+        assert_eq!((before_epoch.hour(), before_epoch.minute(), before_epoch.second()), (23, 59, 59));
+
+        assert!(JalaliDateTime::from_unix(i64::MAX).is_none());
+        assert!(JalaliDateTime::from_unix(i64::MIN).is_none());
+        assert!(date_time < JalaliDateTime::from_unix(1766806015).unwrap());
+    }
+
+    #[test]
+    fn test_jalali_complete_weeks_between() {
+        assert_eq!(jalali_complete_weeks_between((1404, 10, 1), (1404, 10, 14)), 1);
+        assert_eq!(jalali_complete_weeks_between((1404, 10, 1), (1404, 10, 15)), 2);
+        assert_eq!(jalali_complete_weeks_between((1404, 10, 1), (1404, 10, 7)), 0);
+        assert_eq!(jalali_complete_weeks_between((1404, 10, 6), (1404, 10, 6)), 0);
+        // negative spans truncate toward zero
+        assert_eq!(jalali_complete_weeks_between((1404, 10, 14), (1404, 10, 1)), -1);
+        assert_eq!(jalali_complete_weeks_between((1404, 10, 7), (1404, 10, 1)), 0);
+        // across the leap day of 1403
+        assert_eq!(jalali_complete_weeks_between((1403, 1, 1), (1404, 1, 1)), 52);
+    }
+
+    #[test]
+    fn test_iso_week_to_jalali() {
+        assert_eq!(iso_week_to_jalali(2025, 52, 6), Some((1404, 10, 6)));
+        // ISO week 1 of 2025 starts on 2024-12-30
+        assert_eq!(iso_week_to_jalali(2025, 1, 1), Some(gregorian_to_jalali(2024, 12, 30)));
+        // 2026 starts on a Thursday, so it has 53 weeks
+        assert_eq!(iso_week_to_jalali(2026, 53, 7), Some(gregorian_to_jalali(2027, 1, 3)));
+        assert_eq!(iso_week_to_jalali(2027, 1, 1), Some(gregorian_to_jalali(2027, 1, 4)));
+        // leap year starting on a Wednesday
+        assert_eq!(iso_week_to_jalali(2020, 53, 1), Some(gregorian_to_jalali(2020, 12, 28)));
+        assert_eq!(iso_week_to_jalali(2025, 53, 1), None);
+        assert_eq!(iso_week_to_jalali(2025, 0, 1), None);
+        assert_eq!(iso_week_to_jalali(2025, 54, 1), None);
+        assert_eq!(iso_week_to_jalali(2025, 1, 0), None);
+        assert_eq!(iso_week_to_jalali(2025, 1, 8), None);
+        assert_eq!(iso_week_to_jalali(i32::MAX, 1, 1), None);
+        assert_eq!(iso_week_to_jalali(i32::MIN, 1, 1), None);
+    }
+
+    #[test]
+    fn test_unix_to_jalali_with_offset() {
+        assert_eq!(IRAN_STANDARD_OFFSET, 12_600);
+        // 2025-12-27 20:29:59 UTC is 23:59:59 in Tehran, one second later is the next day
+        assert_eq!(unix_to_jalali_with_offset(1766867399, IRAN_STANDARD_OFFSET), Some((1404, 10, 6)));
+        assert_eq!(unix_to_jalali_with_offset(1766867400, IRAN_STANDARD_OFFSET), Some((1404, 10, 7)));
+        // a negative offset moves the date back
+        assert_eq!(unix_to_jalali_with_offset(1766793600, -1), Some((1404, 10, 5)));
+        assert_eq!(unix_to_jalali_with_offset(-1, 0), Some((1348, 10, 10)));
+        assert_eq!(unix_to_jalali_with_offset(i64::MAX, 1), None);
+
+        let tehran_midnight = jalali_to_unix_with_offset(1404, 10, 7, IRAN_STANDARD_OFFSET).unwrap();
+        assert_eq!(tehran_midnight, 1766867400);
+        assert_eq!(unix_to_tehran_day_start(tehran_midnight + 5_000), Some(tehran_midnight));
+        for offset in [-43_200, -3_600, 0, IRAN_STANDARD_OFFSET, 50_400] {
+            let midnight = jalali_to_unix_with_offset(1404, 10, 6, offset).unwrap();
+            assert_eq!(unix_to_jalali_with_offset(midnight, offset), Some((1404, 10, 6)));
+            assert_eq!(unix_to_jalali_with_offset(midnight - 1, offset), Some((1404, 10, 5)));
+        }
+        assert_eq!(jalali_to_unix_with_offset(1404, 10, 6, i32::MIN), Some(1766793600 + 2_147_483_648));
+    }
+
+    #[test]
+    fn test_jalali_day_of_year() {
+        assert_eq!(jalali_day_of_year(1404, 1, 1), Some(1));
+        assert_eq!(jalali_day_of_year(1404, 6, 31), Some(186));
+        assert_eq!(jalali_day_of_year(1404, 7, 1), Some(187));
+        assert_eq!(jalali_day_of_year(1404, 10, 6), Some(282));
+        assert_eq!(jalali_day_of_year(1404, 12, 29), Some(365));
+        assert_eq!(jalali_day_of_year(1403, 12, 30), Some(366));
+        assert_eq!(jalali_day_of_year(1404, 12, 30), None);
+        assert_eq!(jalali_day_of_year(1404, 13, 1), None);
+        assert_eq!(jalali_day_of_year(1404, 1, 0), None);
+
+        for year in [1403, 1404] {
+            let mut ordinal = 0;
+            for month in 1..=12 {
+                for day in 1..=jalali_month_length(year, month) {
+                    ordinal += 1;
+                    assert_eq!(jalali_day_of_year(year, month, day), Some(ordinal));
+                    assert_eq!(jalali_from_day_of_year(year, ordinal), Some((month, day)));
+                }
+            }
+        }
+        assert_eq!(jalali_from_day_of_year(1404, 0), None);
+        assert_eq!(jalali_from_day_of_year(1404, 366), None);
+        assert_eq!(jalali_from_day_of_year(1403, 367), None);
+    }
+
+    #[test]
+    fn test_jalali_date_builder() {
+        let expected = JalaliDate::new(1404, 10, 6).unwrap();
+        assert_eq!(JalaliDateBuilder::new().year(1404).month(10).day(6).build(), Ok(expected));
+        assert_eq!(JalaliDateBuilder::new().day(6).month(10).year(1404).build(), Ok(expected));
+
+        assert_eq!(JalaliDateBuilder::new().build(), Err(JalaliError::MissingYear));
+        assert_eq!(JalaliDateBuilder::new().month(10).day(6).build(), Err(JalaliError::MissingYear));
+        assert_eq!(JalaliDateBuilder::new().year(1404).day(6).build(), Err(JalaliError::MissingMonth));
+        assert_eq!(JalaliDateBuilder::new().year(1404).month(10).build(), Err(JalaliError::MissingDay));
+
+        assert_eq!(JalaliDateBuilder::new().year(1404).month(13).day(6).build(), Err(JalaliError::InvalidMonth));
+        assert_eq!(JalaliDateBuilder::new().year(1404).month(10).day(0).build(), Err(JalaliError::InvalidDay));
+        assert_eq!(JalaliDateBuilder::new().year(1404).month(12).day(30).build(), Err(JalaliError::InvalidDay));
+
+        // a later value replaces an earlier one
+        let mut builder = JalaliDateBuilder::new();
+        builder.year(1404).month(12).day(30);
+        builder.year(1403);
+        assert_eq!(builder.build(), Ok(JalaliDate::new(1403, 12, 30).unwrap()));
+        assert_eq!(JalaliError::MissingDay.to_string(), "missing day");
+    }
+
+    #[test]
+    fn test_jalali_next_anniversary() {
+        assert_eq!(jalali_next_anniversary(10, 6, (1404, 1, 1)), Some((1404, 10, 6)));
+        assert_eq!(jalali_next_anniversary(10, 6, (1404, 10, 6)), Some((1404, 10, 6)));
+        // from just after the anniversary, jump to the next year
+        assert_eq!(jalali_next_anniversary(10, 6, (1404, 10, 7)), Some((1405, 10, 6)));
+        assert_eq!(jalali_next_anniversary(1, 1, (1404, 12, 29)), Some((1405, 1, 1)));
+
+        // Esfand 30 falls on Esfand 29 outside leap years
+        assert_eq!(jalali_next_anniversary(12, 30, (1403, 12, 30)), Some((1403, 12, 30)));
+        assert_eq!(jalali_next_anniversary(12, 30, (1404, 1, 1)), Some((1404, 12, 29)));
+        assert_eq!(jalali_next_anniversary(12, 30, (1404, 12, 29)), Some((1404, 12, 29)));
+        assert_eq!(jalali_next_anniversary(12, 30, (1408, 1, 1)), Some((1408, 12, 30)));
+        assert_eq!(jalali_next_anniversary(12, 30, (1405, 1, 1)), Some((1405, 12, 29)));
+
+        // out-of-range days and months are clamped
+        assert_eq!(jalali_next_anniversary(7, 31, (1404, 1, 1)), Some((1404, 7, 30)));
+        assert_eq!(jalali_next_anniversary(13, 0, (1404, 1, 1)), Some((1404, 12, 1)));
+        // the next occurrence would be after the last representable year
+        assert_eq!(jalali_next_anniversary(1, 1, (i32::MAX, 2, 1)), None);
+        assert_eq!(jalali_next_anniversary(3, 1, (i32::MAX, 2, 1)), Some((i32::MAX, 3, 1)));
+    }
+
+    #[test]
+    fn test_jalali_week_of_year() {
+        // 1404-01-01 is a Friday, the last day of a partial week 1
+        assert_eq!(jalali_week_of_year(1404, 1, 1), Some(1));
+        assert_eq!(jalali_week_of_year(1404, 1, 2), Some(2));
+        assert_eq!(jalali_week_of_year(1404, 1, 8), Some(2));
+        assert_eq!(jalali_week_of_year(1404, 1, 9), Some(3));
+        // 1404-12-29 is a Friday too
+        assert_eq!(jalali_week_of_year(1404, 12, 23), Some(53));
+        assert_eq!(jalali_week_of_year(1404, 12, 29), Some(53));
+        // 1405-01-01 is a Saturday, so week 1 is a full week
+        assert_eq!(jalali_week_of_year(1405, 1, 1), Some(1));
+        assert_eq!(jalali_week_of_year(1405, 1, 7), Some(1));
+        assert_eq!(jalali_week_of_year(1405, 1, 8), Some(2));
+        assert_eq!(jalali_week_of_year(1404, 12, 30), None);
+        assert_eq!(jalali_week_of_year(1404, 0, 1), None);
+    }
+
+    #[test]
+    fn test_jalali_quarter() {
+        let quarters: Vec<_> = (0..=13).map(jalali_quarter).collect();
+        assert_eq!(
+            quarters,
+            vec![None, Some(1), Some(1), Some(1), Some(2), Some(2), Some(2), Some(3), Some(3), Some(3), Some(4),
+                Some(4), Some(4), None]
+        );
+
+        assert_eq!(jalali_quarter_start(1), Some((1, 1)));
+        assert_eq!(jalali_quarter_start(2), Some((4, 1)));
+        assert_eq!(jalali_quarter_start(4), Some((10, 1)));
+        assert_eq!(jalali_quarter_start(0), None);
+        assert_eq!(jalali_quarter_start(5), None);
+
+        assert_eq!(jalali_quarter_end(1404, 2), Some((6, 31)));
+        assert_eq!(jalali_quarter_end(1404, 3), Some((9, 30)));
+        assert_eq!(jalali_quarter_end(1404, 4), Some((12, 29)));
+        assert_eq!(jalali_quarter_end(1403, 4), Some((12, 30)));
+        assert_eq!(jalali_quarter_end(1404, 0), None);
+        assert_eq!(jalali_quarter_end(1404, 5), None);
+
+        // every month lies within its quarter's bounds
+        for month in 1..=12 {
+            let quarter = jalali_quarter(month).unwrap();
+            assert!(jalali_quarter_start(quarter).unwrap().0 <= month);
+            assert!(jalali_quarter_end(1404, quarter).unwrap().0 >= month);
+        }
+    }
+
+    #[test]
+    fn test_prelude() {
+        use crate::prelude::*;
+
+        let date = JalaliDate::new(1404, 10, 6).unwrap();
+        assert_eq!(convert(Calendar::Jalali, Calendar::Gregorian, 1404, 10, 6), Some(date.to_gregorian()));
+        assert_eq!(gregorian_to_jalali(2025, 12, 27), (1404, 10, 6));
+        assert_eq!(unix_to_jalali(1766806014), Some((1404, 10, 6)));
+        assert_eq!(JalaliDateBuilder::new().year(1404).month(10).day(6).build(), Ok(date));
+        assert_eq!(JalaliDateTime::from_unix(1766806014).unwrap().date(), date);
+    }
+
+    #[test]
+    fn test_jalali_season() {
+        assert_eq!(jalali_season(0), None);
+        assert_eq!(jalali_season(3), Some(Season::Bahar));
+        assert_eq!(jalali_season(4), Some(Season::Tabestan));
+        assert_eq!(jalali_season(9), Some(Season::Paiz));
+        assert_eq!(jalali_season(12), Some(Season::Zemestan));
+        assert_eq!(jalali_season(13), None);
+
+        assert_eq!(Season::Bahar.persian_name(), "بهار");
+        assert_eq!(Season::Tabestan.persian_name(), "تابستان");
+        assert_eq!(Season::Paiz.english_name(), "Autumn");
+        assert_eq!(Season::Zemestan.english_name(), "Winter");
+
+        // the season numbering agrees with jalali_season_bounds
+        for month in 1..=12 {
+            let season = jalali_season(month).unwrap();
+            let (first, last) = jalali_season_bounds(1404, season as u32 + 1).unwrap();
+            assert!((first.1..=last.1).contains(&month));
+        }
+    }
+
+    #[test]
+    fn test_jalali_gregorian_jdn_delta() {
+        assert_eq!(jalali_gregorian_jdn_delta(1404, 10, 6), 226_898);
+        assert_eq!(
+            jalali_gregorian_jdn_delta(1404, 10, 6),
+            gregorian_to_jdn(2025, 12, 27) - gregorian_to_jdn(1404, 10, 6)
+        );
+        // 1-01-01 is the epoch of each calendar
+        assert_eq!(jalali_gregorian_jdn_delta(1, 1, 1), epoch::JALALI_EPOCH_JDN - epoch::GREGORIAN_EPOCH_JDN);
+        // the 31-day months of the Jalali first half pull the delta apart during the year
+        assert!(jalali_gregorian_jdn_delta(1404, 7, 1) > jalali_gregorian_jdn_delta(1404, 1, 1));
+    }
+
+    #[test]
+    fn test_convert_ambiguous_date() {
+        let as_jalali = convert_ambiguous_date("1403-12-30", '-', Calendar::Jalali);
+        let as_gregorian = convert_ambiguous_date("1403-12-30", '-', Calendar::Gregorian);
+        assert_eq!(as_jalali, Some("2025-03-20".to_string()));
+        assert_eq!(as_gregorian, parse_gregorian_string_to_jalali_string("1403-12-30", '-'));
+        assert_ne!(as_jalali, as_gregorian);
+
+        // two-digit years are taken literally, not moved into the current century
+        let (gy, gm, gd) = jalali_to_gregorian(99, 1, 1);
+        let expected = format!("{:04}-{:02}-{:02}", gy, gm, gd);
+        assert_eq!(convert_ambiguous_date("99-01-01", '-', Calendar::Jalali), Some(expected));
+        assert_ne!(convert_ambiguous_date("25-12-27", '-', Calendar::Gregorian), Some("1404-10-06".to_string()));
+
+        // digits are normalized
+        assert_eq!(convert_ambiguous_date("2025/12/27", '/', Calendar::Gregorian), Some("1404-10-06".to_string()));
+        assert_eq!(convert_ambiguous_date("۱۴۰۴/۱۰/۰۶", '/', Calendar::Jalali), Some("2025-12-27".to_string()));
+        assert_eq!(
+            convert_ambiguous_date("2025-12-14", '-', Calendar::Julian),
+            convert(Calendar::Julian, Calendar::Jalali, 2025, 12, 14)
+                .map(|(y, m, d)| format!("{:04}-{:02}-{:02}", y, m, d))
+        );
+
+        // validated in the source calendar
+        assert_eq!(convert_ambiguous_date("2025-02-30", '-', Calendar::Gregorian), None);
+        assert!(convert_ambiguous_date("1404-02-30", '-', Calendar::Jalali).is_some());
+        assert_eq!(convert_ambiguous_date("1404-12-30", '-', Calendar::Jalali), None);
+        assert_eq!(convert_ambiguous_date("1404-12", '-', Calendar::Jalali), None);
+        assert_eq!(convert_ambiguous_date("1404-1x-06", '-', Calendar::Jalali), None);
+    }
+
+    #[test]
+    fn test_try_parse_jalali() {
+        let expected = JalaliDate::new(1404, 10, 6).unwrap();
+        assert_eq!(try_parse_jalali("1404/10/06", '/'), Ok(expected));
+        assert_eq!(try_parse_jalali("۱٬۴۰۴-۱۰-۶", '-'), Ok(expected));
+
+        assert_eq!(try_parse_jalali("1404/10", '/'), Err(DateParseError::WrongPartCount));
+        assert_eq!(try_parse_jalali("1404/10/06/1", '/'), Err(DateParseError::WrongPartCount));
+        assert_eq!(try_parse_jalali("1404-10-06", '/'), Err(DateParseError::WrongPartCount));
+        assert_eq!(try_parse_jalali("1404//06", '/'), Err(DateParseError::NotNumeric));
+        assert_eq!(try_parse_jalali("abcd/10/06", '/'), Err(DateParseError::NotNumeric));
+        assert_eq!(try_parse_jalali("1404/-1/06", '/'), Err(DateParseError::NotNumeric));
+        assert_eq!(try_parse_jalali("1404/13/06", '/'), Err(DateParseError::OutOfRange));
+        assert_eq!(try_parse_jalali("1404/10/00", '/'), Err(DateParseError::OutOfRange));
+        assert_eq!(try_parse_jalali("1404/12/30", '/'), Err(DateParseError::OutOfRange));
+        assert_eq!(try_parse_jalali("99999999999/10/06", '/'), Err(DateParseError::OutOfRange));
+        assert_eq!(try_parse_jalali("1404/10/99999999999", '/'), Err(DateParseError::OutOfRange));
+        assert_eq!(DateParseError::NotNumeric.to_string(), "date part is not a number");
+
+        // the Option function agrees with it
+        for input in ["1404/10/06", "1404/13/06", "1404/10", "x/1/1"] {
+            assert_eq!(
+                parse_jalali_string_to_gregorian_string(input, '/').is_some(),
+                try_parse_jalali(input, '/').is_ok()
+            );
+        }
+    }
+
+    #[test]
+    fn test_jalali_year_days() {
+        assert_eq!(jalali_year_days(1403).count(), 366);
+        assert_eq!(jalali_year_days(1404).count(), 365);
+
+        // consecutive dates, one day apart
+        let days: Vec<_> = jalali_year_days(1403).collect();
+        assert_eq!(days[0], (1403, 1, 1));
+        assert_eq!(days[186], (1403, 7, 1));
+        assert_eq!(days[365], (1403, 12, 30));
+        for pair in days.windows(2) {
+            assert_eq!(jalali_add_days(pair[0].0, pair[0].1, pair[0].2, 1), pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_checked_jdn_conversions() {
+        let first_gregorian = gregorian_to_jdn(i32::MIN, 1, 1);
+        let last_gregorian = gregorian_to_jdn(i32::MAX, 12, 31);
+        assert_eq!(checked_jdn_to_gregorian(first_gregorian), Some((i32::MIN, 1, 1)));
+        assert_eq!(checked_jdn_to_gregorian(last_gregorian), Some((i32::MAX, 12, 31)));
+        assert_eq!(checked_jdn_to_gregorian(first_gregorian - 1), None);
+        assert_eq!(checked_jdn_to_gregorian(last_gregorian + 1), None);
+        assert_eq!(checked_jdn_to_gregorian(10_000_000_000_000), None);
+        assert_eq!(checked_jdn_to_gregorian(i64::MAX / 4), None);
+        assert_eq!(checked_jdn_to_gregorian(i64::MAX), None);
+        assert_eq!(checked_jdn_to_gregorian(i64::MIN), None);
+
+        let first_jalali = jalali_to_jdn(i32::MIN, 1, 1);
+        let last_jalali = jalali_to_jdn(i32::MAX, 12, jalali_month_length(i32::MAX, 12));
+        assert_eq!(checked_jdn_to_jalali(first_jalali), Some((i32::MIN, 1, 1)));
+        assert_eq!(checked_jdn_to_jalali(last_jalali), Some((i32::MAX, 12, jalali_month_length(i32::MAX, 12))));
+        assert_eq!(checked_jdn_to_jalali(first_jalali - 1), None);
+        assert_eq!(checked_jdn_to_jalali(last_jalali + 1), None);
+        assert_eq!(checked_jdn_to_jalali(i64::MAX), None);
+        assert_eq!(checked_jdn_to_jalali(i64::MIN), None);
+
+        // in range, the checked and unchecked conversions agree
+        for jdn in [first_jalali, -32_045, 0, epoch::UNIX_EPOCH_JDN, last_gregorian] {
+            assert_eq!(checked_jdn_to_gregorian(jdn), Some(jdn_to_gregorian(jdn)));
+            assert_eq!(checked_jdn_to_jalali(jdn), Some(jdn_to_jalali(jdn)));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Julian Day Number out of range")]
+    fn test_jdn_to_gregorian_panics_at_i64_max() {
+        jdn_to_gregorian(i64::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "Julian Day Number out of range")]
+    fn test_jdn_to_jalali_panics_at_i64_min() {
+        jdn_to_jalali(i64::MIN);
+    }
+
+    #[test]
+    fn test_jalali_date_format() {
+        let date = JalaliDate::new(1404, 10, 6).unwrap();
+        for pattern in ["%d %B %Y", "%A %j", "%Y-%m-%d %%", ""] {
+            assert_eq!(date.format(pattern), format_jalali(1404, 10, 6, pattern));
+        }
+        assert_eq!(date.format("%d %B %Y"), "06 دی 1404");
+    }
+
+    #[test]
+    fn test_format_jalali_unpadded() {
+        assert_eq!(format_jalali(1404, 10, 6, "%-d"), "6");
+        assert_eq!(format_jalali(1404, 10, 6, "%d"), "06");
+        assert_eq!(format_jalali(1404, 9, 6, "%-m/%-d"), "9/6");
+        assert_eq!(format_jalali(1404, 10, 16, "%-m/%-d"), "10/16");
+        // unknown unpadded tokens are copied unchanged
+        assert_eq!(format_jalali(1404, 10, 6, "%-Y %-"), "%-Y %-");
+    }
+
+    #[test]
+    fn test_parse_jalali_datetime() {
+        let date = JalaliDate::new(1404, 10, 6).unwrap();
+        assert_eq!(parse_jalali_datetime("1404-10-06 14:30:15"), JalaliDateTime::new(date, 14, 30, 15));
+        // without seconds
+        assert_eq!(parse_jalali_datetime("1404-10-06 14:30"), JalaliDateTime::new(date, 14, 30, 0));
+        assert_eq!(parse_jalali_datetime("  ۱۴۰۴/۱۰/۰۶\t۰۹:۰۵  "), JalaliDateTime::new(date, 9, 5, 0));
+
+        assert_eq!(parse_jalali_datetime("1404-10-06"), None);
+        assert_eq!(parse_jalali_datetime("1404-10-06 14"), None);
+        assert_eq!(parse_jalali_datetime("1404-10-06 14:30:00:00"), None);
+        assert_eq!(parse_jalali_datetime("1404-10-06 14:30 extra"), None);
+        assert_eq!(parse_jalali_datetime("1404-10-06 14:60"), None);
+        assert_eq!(parse_jalali_datetime("1404-10-06 14:30:60"), None);
+        assert_eq!(parse_jalali_datetime("1404-10-06 14::00"), None);
+        assert_eq!(parse_jalali_datetime("1404-12-30 14:30"), None);
+    }
+
+    #[test]
+    fn test_jalali_compact_timestamp() {
+        let date_time = JalaliDateTime::new(JalaliDate::new(1404, 10, 6).unwrap(), 14, 30, 0).unwrap();
+        assert_eq!(jalali_compact_timestamp(&date_time), "14041006-143000");
+        let early = JalaliDateTime::new(JalaliDate::new(99, 1, 2).unwrap(), 3, 4, 5).unwrap();
+        assert_eq!(jalali_compact_timestamp(&early), "00990102-030405");
+    }
+
+    #[test]
+    fn test_jalali_month_days_with_weekday() {
+        let days: Vec<_> = jalali_month_days_with_weekday(1404, 10).collect();
+        assert_eq!(days.len(), 30);
+        assert_eq!(days[0], (JalaliDate::new(1404, 10, 1).unwrap(), JalaliWeekday::Doshanbe));
+        assert_eq!(days[29], (JalaliDate::new(1404, 10, 30).unwrap(), JalaliWeekday::Seshanbe));
+        // the weekday keeps in step with the date across the whole month
+        for (date, weekday) in &days {
+            assert_eq!(date.weekday(), *weekday);
+        }
+
+        // 1403 is a leap year, so Esfand has 30 days
+        let esfand: Vec<_> = jalali_month_days_with_weekday(1403, 12).collect();
+        assert_eq!(esfand.len(), 30);
+        assert_eq!(esfand[29].1, JalaliWeekday::Panjshanbe);
+        assert_eq!(jalali_month_days_with_weekday(1404, 12).count(), 29);
+        assert_eq!(jalali_month_days_with_weekday(1404, 13).count(), 0);
+    }
+
+    #[test]
+    fn test_jalali_date_min_max() {
+        assert_eq!(JalaliDate::MIN, JalaliDate::new(JALALI_MIN_YEAR, 1, 1).unwrap());
+        assert_eq!((JalaliDate::MAX.year(), JalaliDate::MAX.month()), (JALALI_MAX_YEAR, 12));
+        for date in [JalaliDate::MIN, JalaliDate::MAX] {
+            assert!(is_valid_jalali_date(date.year(), date.month(), date.day()), "{} is invalid", date);
+        }
+        assert!(jalali_is_last_day_of_month(JALALI_MAX_YEAR, 12, JalaliDate::MAX.day()));
+        assert!(JalaliDate::MIN < JalaliDate::MAX);
+        // conversions and arithmetic stay within the bounds
+        for (gy, gm, gd) in [(i32::MAX, 12, 31), (-2_147_482_582, 8, 24), (2025, 12, 27)] {
+            let date = JalaliDate::from_gregorian(gy, gm, gd).unwrap();
+            assert!((JalaliDate::MIN..=JalaliDate::MAX).contains(&date), "{} is out of range", date);
+        }
+        assert_eq!(JalaliDate::from_gregorian(-2_147_482_582, 8, 24), Some(JalaliDate::MIN));
+        assert_eq!(JalaliDate::from_gregorian(-2_147_482_582, 8, 23), None);
+        assert_eq!(JalaliDate::from_gregorian(i32::MIN, 1, 1), None);
+        assert_eq!(JalaliDate::MAX.add_days(-1).add_days(1), JalaliDate::MAX);
+        assert_eq!(JalaliDate::MIN.add_days(1).add_days(-1), JalaliDate::MIN);
+        assert!(JalaliDate::MAX.add_days(-1) < JalaliDate::MAX);
+        assert!(JalaliDate::MIN.add_days(1) > JalaliDate::MIN);
+    }
+
+    #[test]
+    fn test_jalali_validate_or_coerce() {
+        assert_eq!(jalali_validate_or_coerce(1404, 10, 6), (JalaliDate::new(1404, 10, 6).unwrap(), false));
+        assert_eq!(jalali_validate_or_coerce(1404, 12, 30), (JalaliDate::new(1404, 12, 29).unwrap(), true));
+        assert_eq!(jalali_validate_or_coerce(1403, 12, 30), (JalaliDate::new(1403, 12, 30).unwrap(), false));
+        assert_eq!(jalali_validate_or_coerce(1404, 7, 31), (JalaliDate::new(1404, 7, 30).unwrap(), true));
+        assert_eq!(jalali_validate_or_coerce(1404, 0, 0), (JalaliDate::new(1404, 1, 1).unwrap(), true));
+        assert_eq!(jalali_validate_or_coerce(1404, 13, 40), (JalaliDate::new(1404, 12, 29).unwrap(), true));
+        // the coerced date is always valid
+        for (month, day) in [(0, 15), (6, 32), (12, 31), (14, 1)] {
+            let (year, month, day) = jalali_coerce_valid(1404, month, day);
+            assert!(is_valid_jalali_date(year, month, day), "{}-{}-{} is invalid", year, month, day);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_jalali_date_serde() {
+        let date = JalaliDate::new(1404, 10, 6).unwrap();
+        assert_eq!(serde_json::to_string(&date).unwrap(), "\"1404-10-06\"");
+        assert_eq!(serde_json::from_str::<JalaliDate>("\"1404-10-06\"").unwrap(), date);
+        assert_eq!(serde_json::from_str::<JalaliDate>("\"۱۴۰۴-۱۰-۰۶\"").unwrap(), date);
+        assert_eq!(serde_json::from_str::<JalaliDate>("\"١٤٠٤-١٠-٠٦\"").unwrap(), date);
+        // dates that don't exist and other types are rejected
+        let error = serde_json::from_str::<JalaliDate>("\"1404-12-30\"").unwrap_err();
+        assert!(error.to_string().contains("day out of range"), "{}", error);
+        assert!(serde_json::from_str::<JalaliDate>("\"1404-13-01\"").is_err());
+        assert!(serde_json::from_str::<JalaliDate>("14041006").is_err());
+        for date in [JalaliDate::new(1403, 12, 30).unwrap(), JalaliDate::new(1, 1, 1).unwrap()] {
+            let json = serde_json::to_string(&date).unwrap();
+            assert_eq!(serde_json::from_str::<JalaliDate>(&json).unwrap(), date, "{}", json);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_int() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Row {
+            #[serde(with = "crate::serde_int")]
+            date: JalaliDate,
+        }
+
+        let row = Row { date: JalaliDate::new(1404, 10, 6).unwrap() };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"{"date":14041006}"#);
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+        // round trips, including leap days, year 0 and negative years
+        for (year, month, day) in [(1403, 12, 30), (1, 1, 1), (0, 10, 6), (-1, 12, 29), (214748, 12, 29)] {
+            let row = Row { date: JalaliDate::new(year, month, day).unwrap() };
+            let json = serde_json::to_string(&row).unwrap();
+            assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row, "{}", json);
+        }
+        // dates that don't exist, strings and years too large for an i32 are rejected
+        for json in [r#"{"date":14041230}"#, r#"{"date":14041300}"#, r#"{"date":14041000}"#, r#"{"date":"14041006"}"#] {
+            assert!(serde_json::from_str::<Row>(json).is_err(), "{}", json);
+        }
+        assert!(serde_json::to_string(&Row { date: JalaliDate::new(214749, 1, 1).unwrap() }).is_err());
+    }
+
+    #[test]
+    fn test_gregorian_date() {
+        let date = GregorianDate::new(2025, 12, 27).unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2025, 12, 27));
+        assert_eq!(date.to_jalali(), JalaliDate::new(1404, 10, 6));
+        assert_eq!(date.to_string(), "2025-12-27");
+        assert_eq!(GregorianDate::new(2025, 2, 29), None);
+        assert_eq!(GregorianDate::new(2025, 13, 1), None);
+        // adding a day crosses month and year boundaries, leap days included
+        let next = |y, m, d| GregorianDate::new(y, m, d).unwrap() + 1;
+        assert_eq!(next(2025, 1, 31), GregorianDate::new(2025, 2, 1).unwrap());
+        assert_eq!(next(2024, 2, 28), GregorianDate::new(2024, 2, 29).unwrap());
+        assert_eq!(next(2024, 2, 29), GregorianDate::new(2024, 3, 1).unwrap());
+        assert_eq!(next(2025, 2, 28), GregorianDate::new(2025, 3, 1).unwrap());
+        assert_eq!(next(2025, 12, 31), GregorianDate::new(2026, 1, 1).unwrap());
+        assert_eq!(date - 27, GregorianDate::new(2025, 11, 30).unwrap());
+        assert_eq!(date + -27, date - 27);
+        assert_eq!(date - -5, GregorianDate::new(2026, 1, 1).unwrap());
+        // the difference undoes the offset and agrees with the JDN
+        for delta in [-1_000_000, -366, -1, 0, 1, 59, 1_000_000] {
+            assert_eq!((date + delta) - date, delta, "delta {}", delta);
+            assert_eq!(date - (date - delta), delta, "delta {}", delta);
+        }
+        assert!(date < date + 1);
+    }
+
+    #[test]
+    fn test_jalali_date_ops() {
+        let date = JalaliDate::new(1404, 10, 6).unwrap();
+        assert_eq!(date + 1, date.add_days(1));
+        assert_eq!(JalaliDate::new(1403, 12, 30).unwrap() + 1, JalaliDate::new(1404, 1, 1).unwrap());
+        assert_eq!(JalaliDate::new(1404, 1, 1).unwrap() - 1, JalaliDate::new(1403, 12, 30).unwrap());
+        assert_eq!(date - 6, JalaliDate::new(1404, 9, 30).unwrap());
+        for delta in [-1_000_000, -365, -1, 0, 1, 84, 1_000_000] {
+            assert_eq!((date + delta) - date, delta, "delta {}", delta);
+            assert_eq!(date - delta, date.add_days(-delta), "delta {}", delta);
+        }
+        // the two types step in lockstep
+        let gregorian = GregorianDate::new(2025, 12, 27).unwrap();
+        for delta in [-400, -1, 1, 70, 400] {
+            assert_eq!((gregorian + delta).to_jalali(), Some(date + delta), "delta {}", delta);
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_jalali_date_chrono() {
+        use chrono::{Datelike, NaiveDate};
+
+        let naive = NaiveDate::from_ymd_opt(2025, 12, 27).unwrap();
+        let date = JalaliDate::from(naive);
+        assert_eq!(date, JalaliDate::new(1404, 10, 6).unwrap());
+        assert_eq!(date.to_naive_date(), naive);
+        // dates far from the epoch round trip, up to chrono's own limits
+        let far_dates = [
+            NaiveDate::MIN,
+            NaiveDate::MAX,
+            NaiveDate::from_ymd_opt(-100_000, 2, 29).unwrap(),
+            NaiveDate::from_ymd_opt(1, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(622, 3, 21).unwrap(),
+            NaiveDate::from_ymd_opt(200_000, 12, 31).unwrap(),
+        ];
+        for naive in far_dates {
+            let date = JalaliDate::from(naive);
+            assert_eq!(date.to_naive_date(), naive, "{}", naive);
+            let expected = gregorian_to_jalali(naive.year(), naive.month() as usize, naive.day() as i32);
+            assert_eq!((date.year(), date.month(), date.day()), expected, "{}", naive);
+        }
+        // day by day across a leap year and its neighbours
+        let mut naive = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        while naive.year() < 2026 {
+            assert_eq!(JalaliDate::from(naive).to_naive_date(), naive, "{}", naive);
+            naive = naive.succ_opt().unwrap();
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    #[should_panic(expected = "date outside chrono's NaiveDate range")]
+    fn test_jalali_date_to_naive_date_out_of_range() {
+        JalaliDate::MAX.to_naive_date();
     }
 }
\ No newline at end of file